@@ -0,0 +1,239 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::{Confirm, Opt};
+use crate::val_helpr::ValidationResult;
+
+/// # Checkboxes
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements#checkboxes)
+///
+/// Works with the following block types: Section, Actions, Input
+///
+/// A checkbox group that allows a user to choose multiple items from a
+/// list of options.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+#[validate(schema(function = "validation::initial_options_subset_of_options"))]
+pub struct Checkboxes<'a> {
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[validate]
+    #[validate(length(max = 10))]
+    options: Vec<Opt>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    #[validate(length(max = 10))]
+    initial_options: Option<Vec<Opt>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> Checkboxes<'a> {
+    /// Build a new checkbox group.
+    ///
+    /// # Example
+    /// see example for `build::CheckboxesBuilder`.
+    pub fn builder() -> build::CheckboxesBuilderInit<'a> {
+        build::CheckboxesBuilderInit::new()
+    }
+
+    /// Validate that this checkbox group agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `options` or `initial_options` has more than 10 elements
+    /// - If any contained `Opt` is itself invalid
+    /// - If `initial_options` contains a value not present in `options`
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+
+    pub(crate) fn action_id(&self) -> &str {
+        &self.action_id
+    }
+
+    pub(crate) fn options(&self) -> &[Opt] {
+        &self.options
+    }
+
+    pub(crate) fn initial_options(&self) -> Option<&[Opt]> {
+        self.initial_options.as_deref()
+    }
+
+    pub(crate) fn confirm(&self) -> Option<&Confirm> {
+        self.confirm.as_ref()
+    }
+}
+
+mod validation {
+    use crate::val_helpr::{is_subset, ValidatorResult};
+
+    use super::Checkboxes;
+
+    pub fn initial_options_subset_of_options(cb: &Checkboxes) -> ValidatorResult {
+        match &cb.initial_options {
+            None => Ok(()),
+            Some(initial) => is_subset("initial_options", initial, &cb.options),
+        }
+    }
+}
+
+/// Checkboxes builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// CheckboxesBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+        /// CheckboxesBuilder.options
+        #[derive(Copy, Clone, Debug)]
+        pub struct options;
+    }
+
+    /// Initial state for the Checkboxes builder
+    pub type CheckboxesBuilderInit<'a> = CheckboxesBuilder<
+        'a,
+        RequiredMethodNotCalled<method::action_id>,
+        RequiredMethodNotCalled<method::options>,
+    >;
+
+    /// Checkboxes builder
+    ///
+    /// # Required Methods
+    /// `CheckboxesBuilder::build()` is only available if these methods have been called:
+    ///  - `action_id`
+    ///  - `options`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::Checkboxes;
+    /// use slack_blocks::compose::Opt;
+    ///
+    /// let checkboxes = Checkboxes::builder()
+    ///     .action_id("notif_settings")
+    ///     .options(vec![Opt::new("Email me", "email")])
+    ///     .build();
+    /// ```
+    #[derive(Debug)]
+    pub struct CheckboxesBuilder<'a, A, O> {
+        action_id: Option<Cow<'a, str>>,
+        options: Option<Vec<Opt>>,
+        initial_options: Option<Vec<Opt>>,
+        confirm: Option<Confirm>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<(A, O)>,
+    }
+
+    impl<'a, A, O> CheckboxesBuilder<'a, A, O> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            CheckboxesBuilder {
+                action_id: None,
+                options: None,
+                initial_options: None,
+                confirm: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        ///
+        /// An identifier for this action, used to identify the source of
+        /// interaction payloads. Must be unique within a block.
+        /// Maximum length for this field is 255 characters.
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> CheckboxesBuilder<'a, Set<method::action_id>, O> {
+            CheckboxesBuilder {
+                action_id: Some(action_id.into()),
+                options: self.options,
+                initial_options: self.initial_options,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `options` (**Required**)
+        ///
+        /// An array of [option objects 🔗]. Maximum of 10 options.
+        ///
+        /// [option objects 🔗]: https://api.slack.com/reference/block-kit/composition-objects#option
+        pub fn options(
+            self,
+            options: impl IntoIterator<Item = impl Into<Opt>>,
+        ) -> CheckboxesBuilder<'a, A, Set<method::options>> {
+            CheckboxesBuilder {
+                action_id: self.action_id,
+                options: Some(options.into_iter().map(Into::into).collect()),
+                initial_options: self.initial_options,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `initial_options` (Optional)
+        ///
+        /// An array of [option objects 🔗] that are pre-checked when the
+        /// checkbox group is first rendered. Must be a subset of `options`.
+        ///
+        /// [option objects 🔗]: https://api.slack.com/reference/block-kit/composition-objects#option
+        pub fn initial_options(
+            mut self,
+            initial_options: impl IntoIterator<Item = impl Into<Opt>>,
+        ) -> Self {
+            self.initial_options = Some(initial_options.into_iter().map(Into::into).collect());
+            self
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user changes their
+        /// selection, e.g. "Are you sure you want to opt into these emails?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this checkbox group should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> CheckboxesBuilder<'a, Set<method::action_id>, Set<method::options>> {
+        /// All done building, now give me a checkbox group!
+        ///
+        /// > `no method name 'build' found for struct 'CheckboxesBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `CheckboxesBuilder`.
+        pub fn build(self) -> Checkboxes<'a> {
+            Checkboxes {
+                action_id: self.action_id.unwrap(),
+                options: self.options.unwrap(),
+                initial_options: self.initial_options,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::Confirm;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// # Date Picker
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements#datepicker)
+///
+/// Works with the following block types: Section, Actions, Input
+///
+/// An element which lets users easily select a date from a calendar.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct DatePicker<'a> {
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<text::Plain>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_date: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> DatePicker<'a> {
+    /// Build a new date picker.
+    ///
+    /// # Example
+    /// see example for `build::DatePickerBuilder`.
+    pub fn builder() -> build::DatePickerBuilderInit<'a> {
+        build::DatePickerBuilderInit::new()
+    }
+
+    /// Validate that this date picker agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+/// Date picker builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// DatePickerBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the DatePicker builder
+    pub type DatePickerBuilderInit<'a> =
+        DatePickerBuilder<'a, RequiredMethodNotCalled<method::action_id>>;
+
+    /// Date picker builder
+    ///
+    /// # Required Methods
+    /// `DatePickerBuilder::build()` is only available if these methods have been called:
+    ///  - `action_id`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::DatePicker;
+    ///
+    /// let picker = DatePicker::builder().action_id("birthday_picker").build();
+    /// ```
+    #[derive(Debug)]
+    pub struct DatePickerBuilder<'a, A> {
+        action_id: Option<Cow<'a, str>>,
+        placeholder: Option<text::Plain>,
+        initial_date: Option<String>,
+        confirm: Option<Confirm>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<A>,
+    }
+
+    impl<'a, A> DatePickerBuilder<'a, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            DatePickerBuilder {
+                action_id: None,
+                placeholder: None,
+                initial_date: None,
+                confirm: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        ///
+        /// An identifier for this action, used to identify the source of
+        /// interaction payloads. Must be unique within a block.
+        /// Maximum length for this field is 255 characters.
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> DatePickerBuilder<'a, Set<method::action_id>> {
+            DatePickerBuilder {
+                action_id: Some(action_id.into()),
+                placeholder: self.placeholder,
+                initial_date: self.initial_date,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (Optional)
+        ///
+        /// A `plain_text` string shown in the picker until a date is selected.
+        pub fn placeholder(mut self, placeholder: impl Into<text::Plain>) -> Self {
+            self.placeholder = Some(placeholder.into());
+            self
+        }
+
+        /// Set `initial_date` (Optional)
+        ///
+        /// The initial date selected when the picker is first rendered,
+        /// in `YYYY-MM-DD` format.
+        pub fn initial_date(mut self, initial_date: impl ToString) -> Self {
+            self.initial_date = Some(initial_date.to_string());
+            self
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user selects a
+        /// date, e.g. "Are you sure you want to pick this date?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this date picker should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> DatePickerBuilder<'a, Set<method::action_id>> {
+        /// All done building, now give me a date picker!
+        ///
+        /// > `no method name 'build' found for struct 'DatePickerBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `DatePickerBuilder`.
+        pub fn build(self) -> DatePicker<'a> {
+            DatePicker {
+                action_id: self.action_id.unwrap(),
+                placeholder: self.placeholder,
+                initial_date: self.initial_date,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::Confirm;
+use crate::val_helpr::ValidationResult;
+
+/// # Date & Time Picker
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements#datetimepicker)
+///
+/// Works with the following block types: Section, Actions, Input
+///
+/// An element which lets users easily select both a date and a time of day,
+/// formatted as a Unix timestamp.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct DateTimePicker<'a> {
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_date_time: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> DateTimePicker<'a> {
+    /// Build a new date & time picker.
+    ///
+    /// # Example
+    /// see example for `build::DateTimePickerBuilder`.
+    pub fn builder() -> build::DateTimePickerBuilderInit<'a> {
+        build::DateTimePickerBuilderInit::new()
+    }
+
+    /// Validate that this date & time picker agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+/// Date & time picker builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// DateTimePickerBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the DateTimePicker builder
+    pub type DateTimePickerBuilderInit<'a> =
+        DateTimePickerBuilder<'a, RequiredMethodNotCalled<method::action_id>>;
+
+    /// Date & time picker builder
+    ///
+    /// # Required Methods
+    /// `DateTimePickerBuilder::build()` is only available if these methods have been called:
+    ///  - `action_id`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::DateTimePicker;
+    ///
+    /// let picker = DateTimePicker::builder().action_id("meeting_time").build();
+    /// ```
+    #[derive(Debug)]
+    pub struct DateTimePickerBuilder<'a, A> {
+        action_id: Option<Cow<'a, str>>,
+        initial_date_time: Option<u64>,
+        confirm: Option<Confirm>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<A>,
+    }
+
+    impl<'a, A> DateTimePickerBuilder<'a, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            DateTimePickerBuilder {
+                action_id: None,
+                initial_date_time: None,
+                confirm: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        ///
+        /// An identifier for this action, used to identify the source of
+        /// interaction payloads. Must be unique within a block.
+        /// Maximum length for this field is 255 characters.
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> DateTimePickerBuilder<'a, Set<method::action_id>> {
+            DateTimePickerBuilder {
+                action_id: Some(action_id.into()),
+                initial_date_time: self.initial_date_time,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `initial_date_time` (Optional)
+        ///
+        /// The initial date and time selected when the picker is first
+        /// rendered, as a Unix timestamp (seconds since the epoch).
+        pub fn initial_date_time(mut self, initial_date_time: u64) -> Self {
+            self.initial_date_time = Some(initial_date_time);
+            self
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user selects a
+        /// date and time, e.g. "Are you sure you want to pick this time?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Indicates whether the element will be set to autofocus within
+        /// the view object.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> DateTimePickerBuilder<'a, Set<method::action_id>> {
+        /// All done building, now give me a date & time picker!
+        ///
+        /// > `no method name 'build' found for struct 'DateTimePickerBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `DateTimePickerBuilder`.
+        pub fn build(self) -> DateTimePicker<'a> {
+            DateTimePicker {
+                action_id: self.action_id.unwrap(),
+                initial_date_time: self.initial_date_time,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
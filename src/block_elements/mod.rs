@@ -0,0 +1,236 @@
+//! # Block Elements
+//!
+//! [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements)
+//!
+//! Block elements can be used inside of `section`, `context`, `actions`,
+//! and `input` blocks to add interactivity (buttons, menus, inputs, etc)
+//! to a message or modal.
+//!
+//! To use interactive components, you will need to make some changes to
+//! prepare your app. Read the [guide to enabling interactivity 🔗].
+//!
+//! [guide to enabling interactivity 🔗]: https://api.slack.com/interactivity/handling
+
+use serde::{Deserialize, Serialize};
+
+use crate::compose::Confirm;
+use crate::convert;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+mod checkboxes;
+pub use checkboxes::Checkboxes;
+
+mod date_picker;
+pub use date_picker::DatePicker;
+
+mod date_time_picker;
+pub use date_time_picker::DateTimePicker;
+
+mod email_input;
+pub use email_input::EmailInput;
+
+pub mod multi_select_input;
+pub use multi_select_input::MultiSelectInput;
+
+mod number_input;
+pub use number_input::NumberInput;
+
+mod overflow_menu;
+pub use overflow_menu::OverflowMenu;
+
+mod radio_buttons;
+pub use radio_buttons::RadioButtons;
+
+pub mod select;
+
+mod text_input;
+pub use text_input::PlainTextInput;
+
+mod url_input;
+pub use url_input::UrlInput;
+
+/// # Button Element
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements#button)
+///
+/// Works with the following block types: Section, Actions
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct Button {
+    text: text::Plain,
+    action_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<ButtonStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<Confirm>,
+}
+
+impl Button {
+    /// Construct a Button from its required fields - the text on the button,
+    /// and an `action_id` used to identify interactions with it.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::Button;
+    ///
+    /// let button = Button::from_text_and_action_id("Click me!", "click_me_123");
+    /// ```
+    pub fn from_text_and_action_id(
+        text: impl Into<text::Plain>,
+        action_id: impl ToString,
+    ) -> Self {
+        Button {
+            text: text.into(),
+            action_id: action_id.to_string(),
+            value: None,
+            url: None,
+            style: None,
+            confirm: None,
+        }
+    }
+
+    /// Set the `value` sent back to your app when the button is clicked.
+    pub fn with_value(mut self, value: impl ToString) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    /// Set a URL to load in the user's browser when the button is clicked.
+    ///
+    /// Doing so will still send an interaction payload to your app, and
+    /// should be used alongside `value`, not as a replacement for it.
+    pub fn with_url(mut self, url: impl ToString) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    /// Color the button as `primary` or `danger`, instead of the default style.
+    pub fn with_style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Set a confirmation dialog that pops up before the button's interaction
+    /// payload is sent, e.g. "Are you sure you want to delete this channel?".
+    pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Validate that this Button agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `confirm` is set and invalid (see `Confirm::validate`)
+    pub fn validate(&self) -> ValidationResult {
+        if let Some(confirm) = &self.confirm {
+            confirm.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The decoration applied to a `Button`.
+#[derive(Copy, Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    Primary,
+    Danger,
+}
+
+/// Every `BlockElement` that this crate is aware of, regardless of which
+/// blocks it is actually legal to put it in.
+///
+/// Individual blocks (`actions::BlockElement`, `input::InputElement`, ...)
+/// expose a narrower, block-specific subset and convert to/from this type.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum BlockElement<'a> {
+    #[serde(rename = "button")]
+    Button(Button),
+
+    #[serde(rename = "static_select")]
+    SelectStatic(select::Static<'a>),
+
+    #[serde(rename = "external_select")]
+    SelectExternal(select::External<'a>),
+
+    #[serde(rename = "users_select")]
+    SelectUser(select::User<'a>),
+
+    #[serde(rename = "conversations_select")]
+    SelectConversation(select::Conversation<'a>),
+
+    #[serde(rename = "channels_select")]
+    SelectPublicChannel(select::PublicChannel<'a>),
+
+    #[serde(rename = "multi_static_select")]
+    SelectMultiStatic(select::multi::MultiStatic<'a>),
+
+    #[serde(rename = "multi_external_select")]
+    SelectMultiExternal(select::multi::MultiExternal<'a>),
+
+    #[serde(rename = "multi_users_select")]
+    SelectMultiUser(select::multi::MultiUser<'a>),
+
+    #[serde(rename = "multi_conversations_select")]
+    SelectMultiConversation(select::multi::MultiConversation<'a>),
+
+    #[serde(rename = "multi_channels_select")]
+    SelectMultiPublicChannel(select::multi::MultiPublicChannel<'a>),
+
+    #[serde(rename = "checkboxes")]
+    Checkboxes(Checkboxes<'a>),
+
+    #[serde(rename = "datepicker")]
+    DatePicker(DatePicker<'a>),
+
+    #[serde(rename = "datetimepicker")]
+    DateTimePicker(DateTimePicker<'a>),
+
+    #[serde(rename = "email_text_input")]
+    EmailInput(EmailInput<'a>),
+
+    #[serde(rename = "number_input")]
+    NumberInput(NumberInput<'a>),
+
+    #[serde(rename = "overflow")]
+    OverflowMenu(OverflowMenu<'a>),
+
+    #[serde(rename = "plain_text_input")]
+    PlainInput(PlainTextInput<'a>),
+
+    #[serde(rename = "radio_buttons")]
+    RadioButtons(RadioButtons<'a>),
+
+    #[serde(rename = "url_text_input")]
+    UrlInput(UrlInput<'a>),
+}
+
+convert!(impl From<Button> for BlockElement<'static> => |b| BlockElement::Button(b));
+convert!(impl<'a> From<select::Static<'a>> for BlockElement<'a> => |s| BlockElement::SelectStatic(s));
+convert!(impl<'a> From<select::External<'a>> for BlockElement<'a> => |s| BlockElement::SelectExternal(s));
+convert!(impl<'a> From<select::User<'a>> for BlockElement<'a> => |s| BlockElement::SelectUser(s));
+convert!(impl<'a> From<select::Conversation<'a>> for BlockElement<'a> => |s| BlockElement::SelectConversation(s));
+convert!(impl<'a> From<select::PublicChannel<'a>> for BlockElement<'a> => |s| BlockElement::SelectPublicChannel(s));
+convert!(impl<'a> From<select::multi::MultiStatic<'a>> for BlockElement<'a> => |s| BlockElement::SelectMultiStatic(s));
+convert!(impl<'a> From<select::multi::MultiExternal<'a>> for BlockElement<'a> => |s| BlockElement::SelectMultiExternal(s));
+convert!(impl<'a> From<select::multi::MultiUser<'a>> for BlockElement<'a> => |s| BlockElement::SelectMultiUser(s));
+convert!(impl<'a> From<select::multi::MultiConversation<'a>> for BlockElement<'a> => |s| BlockElement::SelectMultiConversation(s));
+convert!(impl<'a> From<select::multi::MultiPublicChannel<'a>> for BlockElement<'a> => |s| BlockElement::SelectMultiPublicChannel(s));
+convert!(impl<'_> From<Checkboxes> for BlockElement => |c| BlockElement::Checkboxes(c));
+convert!(impl<'_> From<DatePicker> for BlockElement => |d| BlockElement::DatePicker(d));
+convert!(impl<'_> From<DateTimePicker> for BlockElement => |d| BlockElement::DateTimePicker(d));
+convert!(impl<'_> From<EmailInput> for BlockElement => |e| BlockElement::EmailInput(e));
+convert!(impl<'_> From<NumberInput> for BlockElement => |n| BlockElement::NumberInput(n));
+convert!(impl<'_> From<OverflowMenu> for BlockElement => |o| BlockElement::OverflowMenu(o));
+convert!(impl<'_> From<PlainTextInput> for BlockElement => |p| BlockElement::PlainInput(p));
+convert!(impl<'_> From<RadioButtons> for BlockElement => |r| BlockElement::RadioButtons(r));
+convert!(impl<'_> From<UrlInput> for BlockElement => |u| BlockElement::UrlInput(u));
@@ -0,0 +1,154 @@
+//! # Multi-Select Input
+//!
+//! `Checkboxes`, `RadioButtons`, `OverflowMenu`, and `MultiStatic` all boil
+//! down to the same shape: an `action_id`, a list of `options` to choose
+//! from, (usually) a set of those pre-selected as `initial_options`, and an
+//! optional `confirm` dialog. `MultiSelectInput` exposes that shape as a
+//! trait, so code that only cares about "some options were picked" - a
+//! summary renderer, the payload decoding in `response::state` - can be
+//! written once against `&dyn MultiSelectInput` instead of matching every
+//! concrete element.
+
+use crate::block_elements::select::multi::MultiStatic;
+use crate::block_elements::{Checkboxes, OverflowMenu, RadioButtons};
+use crate::compose::{Confirm, Opt};
+
+/// Common shape shared by the block elements that let a user pick from a
+/// list of `Opt`s.
+pub trait MultiSelectInput {
+    /// This element's `action_id`.
+    fn action_id(&self) -> &str;
+
+    /// The full list of options a user may choose from.
+    fn options(&self) -> Vec<&Opt>;
+
+    /// The options pre-selected when this element is first rendered.
+    ///
+    /// Empty for elements with nothing selected, and for elements (like
+    /// `OverflowMenu`) that don't support an initial selection at all.
+    fn initial_options(&self) -> Vec<&Opt>;
+
+    /// The confirmation dialog shown before this element's selection takes
+    /// effect, if one was set.
+    fn confirm(&self) -> Option<&Confirm>;
+}
+
+impl<'a> MultiSelectInput for Checkboxes<'a> {
+    fn action_id(&self) -> &str {
+        Checkboxes::action_id(self)
+    }
+
+    fn options(&self) -> Vec<&Opt> {
+        Checkboxes::options(self).iter().collect()
+    }
+
+    fn initial_options(&self) -> Vec<&Opt> {
+        Checkboxes::initial_options(self).into_iter().flatten().collect()
+    }
+
+    fn confirm(&self) -> Option<&Confirm> {
+        Checkboxes::confirm(self)
+    }
+}
+
+impl<'a> MultiSelectInput for RadioButtons<'a> {
+    fn action_id(&self) -> &str {
+        RadioButtons::action_id(self)
+    }
+
+    fn options(&self) -> Vec<&Opt> {
+        RadioButtons::options(self).iter().collect()
+    }
+
+    fn initial_options(&self) -> Vec<&Opt> {
+        RadioButtons::initial_option(self).into_iter().collect()
+    }
+
+    fn confirm(&self) -> Option<&Confirm> {
+        RadioButtons::confirm(self)
+    }
+}
+
+impl<'a> MultiSelectInput for OverflowMenu<'a> {
+    fn action_id(&self) -> &str {
+        OverflowMenu::action_id(self)
+    }
+
+    fn options(&self) -> Vec<&Opt> {
+        OverflowMenu::options(self).iter().collect()
+    }
+
+    fn initial_options(&self) -> Vec<&Opt> {
+        Vec::new()
+    }
+
+    fn confirm(&self) -> Option<&Confirm> {
+        OverflowMenu::confirm(self)
+    }
+}
+
+impl<'a> MultiSelectInput for MultiStatic<'a> {
+    fn action_id(&self) -> &str {
+        MultiStatic::action_id(self)
+    }
+
+    fn options(&self) -> Vec<&Opt> {
+        MultiStatic::options(self)
+            .into_iter()
+            .flatten()
+            .chain(
+                MultiStatic::option_groups(self)
+                    .into_iter()
+                    .flatten()
+                    .flat_map(|group| group.options()),
+            )
+            .collect()
+    }
+
+    fn initial_options(&self) -> Vec<&Opt> {
+        MultiStatic::initial_options(self).into_iter().flatten().collect()
+    }
+
+    fn confirm(&self) -> Option<&Confirm> {
+        MultiStatic::confirm(self)
+    }
+}
+
+/// Generic validation helpers written once against `MultiSelectInput`,
+/// rather than duplicated per concrete element.
+///
+/// These aren't wired into any element's `#[validate(schema(...))]` - the
+/// `validator` crate's schema functions must take the concrete struct, not
+/// a generic/trait-object parameter - but they're handy for downstream code
+/// that wants to check an arbitrary `&dyn MultiSelectInput`.
+pub mod validation {
+    use crate::val_helpr::{error, is_subset, ValidatorResult};
+
+    use super::MultiSelectInput;
+
+    /// Check that `input.options()` has no more than `max` elements.
+    pub fn options_len_leq(input: &(impl MultiSelectInput + ?Sized), max: usize) -> ValidatorResult {
+        let len = input.options().len();
+
+        if len > max {
+            Err(error(
+                "options_len_leq",
+                format!("expected at most {} options, got {}", max, len),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Check that every one of `input.initial_options()` is also present in
+    /// `input.options()`.
+    pub fn initial_options_subset_of_options(
+        input: &(impl MultiSelectInput + ?Sized),
+    ) -> ValidatorResult {
+        is_subset(
+            "initial_options",
+            input.initial_options(),
+            input.options(),
+        )
+    }
+}
@@ -0,0 +1,264 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::DispatchActionConfig;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// # Number Input
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements#number)
+///
+/// Works with the following block types: Input
+///
+/// Allows user to enter a number, either whole or decimal.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+#[validate(schema(function = "validation::min_value_lte_max_value"))]
+pub struct NumberInput<'a> {
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    is_decimal_allowed: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<text::Plain>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+
+    #[serde(rename = "dispatch_action_configuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    dispatch_action_config: Option<DispatchActionConfig>,
+}
+
+impl<'a> NumberInput<'a> {
+    /// Build a new number input.
+    ///
+    /// # Example
+    /// see example for `build::NumberInputBuilder`.
+    pub fn builder() -> build::NumberInputBuilderInit<'a> {
+        build::NumberInputBuilderInit::new()
+    }
+
+    /// Validate that this number input agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `min_value` and `max_value` are both set, and `min_value` is greater than `max_value`
+    /// - If `dispatch_action_config` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+mod validation {
+    use super::*;
+    use crate::val_helpr::{error, ValidatorResult};
+
+    pub fn min_value_lte_max_value(input: &NumberInput) -> ValidatorResult {
+        let min = input.min_value.as_ref().and_then(|s| s.parse::<f64>().ok());
+        let max = input.max_value.as_ref().and_then(|s| s.parse::<f64>().ok());
+
+        match (min, max) {
+            (Some(min), Some(max)) if min > max => Err(error(
+                "min_value_lte_max_value",
+                "`min_value` must be less than or equal to `max_value`",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Number input builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// NumberInputBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+        /// NumberInputBuilder.is_decimal_allowed
+        #[derive(Copy, Clone, Debug)]
+        pub struct is_decimal_allowed;
+    }
+
+    /// Initial state for the NumberInput builder
+    pub type NumberInputBuilderInit<'a> = NumberInputBuilder<
+        'a,
+        RequiredMethodNotCalled<method::action_id>,
+        RequiredMethodNotCalled<method::is_decimal_allowed>,
+    >;
+
+    /// Number input builder
+    ///
+    /// # Required Methods
+    /// `NumberInputBuilder::build()` is only available if these methods have been called:
+    ///  - `action_id`
+    ///  - `is_decimal_allowed`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::NumberInput;
+    ///
+    /// let input = NumberInput::builder()
+    ///     .action_id("age_field")
+    ///     .is_decimal_allowed(false)
+    ///     .build();
+    /// ```
+    #[derive(Debug)]
+    pub struct NumberInputBuilder<'a, A, D> {
+        action_id: Option<Cow<'a, str>>,
+        is_decimal_allowed: Option<bool>,
+        placeholder: Option<text::Plain>,
+        initial_value: Option<String>,
+        min_value: Option<String>,
+        max_value: Option<String>,
+        focus_on_load: Option<bool>,
+        dispatch_action_config: Option<DispatchActionConfig>,
+        state: std::marker::PhantomData<(A, D)>,
+    }
+
+    impl<'a, A, D> NumberInputBuilder<'a, A, D> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            NumberInputBuilder {
+                action_id: None,
+                is_decimal_allowed: None,
+                placeholder: None,
+                initial_value: None,
+                min_value: None,
+                max_value: None,
+                focus_on_load: None,
+                dispatch_action_config: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        ///
+        /// An identifier for this action, used to identify the source of
+        /// interaction payloads. Must be unique within a block.
+        /// Maximum length for this field is 255 characters.
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> NumberInputBuilder<'a, Set<method::action_id>, D> {
+            NumberInputBuilder {
+                action_id: Some(action_id.into()),
+                is_decimal_allowed: self.is_decimal_allowed,
+                placeholder: self.placeholder,
+                initial_value: self.initial_value,
+                min_value: self.min_value,
+                max_value: self.max_value,
+                focus_on_load: self.focus_on_load,
+                dispatch_action_config: self.dispatch_action_config,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `is_decimal_allowed` (**Required**)
+        ///
+        /// Whether the user is allowed to enter a decimal value, or
+        /// restricted to whole numbers.
+        pub fn is_decimal_allowed(
+            self,
+            is_decimal_allowed: bool,
+        ) -> NumberInputBuilder<'a, A, Set<method::is_decimal_allowed>> {
+            NumberInputBuilder {
+                action_id: self.action_id,
+                is_decimal_allowed: Some(is_decimal_allowed),
+                placeholder: self.placeholder,
+                initial_value: self.initial_value,
+                min_value: self.min_value,
+                max_value: self.max_value,
+                focus_on_load: self.focus_on_load,
+                dispatch_action_config: self.dispatch_action_config,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (Optional)
+        ///
+        /// A `plain_text` string shown in the input until a value is typed.
+        pub fn placeholder(mut self, placeholder: impl Into<text::Plain>) -> Self {
+            self.placeholder = Some(placeholder.into());
+            self
+        }
+
+        /// Set `initial_value` (Optional)
+        ///
+        /// The initial value in the number input when it is first rendered.
+        pub fn initial_value(mut self, initial_value: impl ToString) -> Self {
+            self.initial_value = Some(initial_value.to_string());
+            self
+        }
+
+        /// Set `min_value` (Optional)
+        ///
+        /// The minimum value that can be entered.
+        pub fn min_value(mut self, min_value: impl ToString) -> Self {
+            self.min_value = Some(min_value.to_string());
+            self
+        }
+
+        /// Set `max_value` (Optional)
+        ///
+        /// The maximum value that can be entered.
+        pub fn max_value(mut self, max_value: impl ToString) -> Self {
+            self.max_value = Some(max_value.to_string());
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this input should be focused as soon as the containing
+        /// surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+
+        /// Set `dispatch_action_config` (Optional)
+        ///
+        /// Configures the events that cause this input to dispatch a
+        /// `block_actions` payload, e.g. on every keystroke rather than
+        /// waiting for the surface to be submitted.
+        pub fn dispatch_action_config(mut self, config: DispatchActionConfig) -> Self {
+            self.dispatch_action_config = Some(config);
+            self
+        }
+    }
+
+    impl<'a> NumberInputBuilder<'a, Set<method::action_id>, Set<method::is_decimal_allowed>> {
+        /// All done building, now give me a number input!
+        ///
+        /// > `no method name 'build' found for struct 'NumberInputBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `NumberInputBuilder`.
+        pub fn build(self) -> NumberInput<'a> {
+            NumberInput {
+                action_id: self.action_id.unwrap(),
+                is_decimal_allowed: self.is_decimal_allowed.unwrap(),
+                placeholder: self.placeholder,
+                initial_value: self.initial_value,
+                min_value: self.min_value,
+                max_value: self.max_value,
+                focus_on_load: self.focus_on_load,
+                dispatch_action_config: self.dispatch_action_config,
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::{Confirm, Opt};
+use crate::val_helpr::ValidationResult;
+
+/// # Overflow Menu
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements#overflow)
+///
+/// Works with the following block types: Section, Actions
+///
+/// This is like a cross between a button and a select menu - when a user
+/// clicks on this overflow button, they will be presented with a list of
+/// options to choose from. Unlike the select menu, there is no typeahead
+/// field, and the button always appears with an ellipsis ("...") rather
+/// than customizable text.
+///
+/// As such, it is usually used if you want a more compact alternative to
+/// a select menu.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct OverflowMenu<'a> {
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[validate]
+    #[validate(length(min = 2, max = 5))]
+    options: Vec<Opt>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+}
+
+impl<'a> OverflowMenu<'a> {
+    /// Build a new overflow menu.
+    ///
+    /// # Example
+    /// see example for `build::OverflowMenuBuilder`.
+    pub fn builder() -> build::OverflowMenuBuilderInit<'a> {
+        build::OverflowMenuBuilderInit::new()
+    }
+
+    /// Validate that this overflow menu agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `options` has fewer than 2 or more than 5 elements
+    /// - If any contained `Opt` is itself invalid
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+
+    pub(crate) fn action_id(&self) -> &str {
+        &self.action_id
+    }
+
+    pub(crate) fn options(&self) -> &[Opt] {
+        &self.options
+    }
+
+    pub(crate) fn confirm(&self) -> Option<&Confirm> {
+        self.confirm.as_ref()
+    }
+}
+
+/// Overflow menu builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// OverflowMenuBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+        /// OverflowMenuBuilder.options
+        #[derive(Copy, Clone, Debug)]
+        pub struct options;
+    }
+
+    /// Initial state for the OverflowMenu builder
+    pub type OverflowMenuBuilderInit<'a> = OverflowMenuBuilder<
+        'a,
+        RequiredMethodNotCalled<method::action_id>,
+        RequiredMethodNotCalled<method::options>,
+    >;
+
+    /// Overflow menu builder
+    ///
+    /// # Required Methods
+    /// `OverflowMenuBuilder::build()` is only available if these methods have been called:
+    ///  - `action_id`
+    ///  - `options`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::OverflowMenu;
+    /// use slack_blocks::compose::Opt;
+    ///
+    /// let menu = OverflowMenu::builder()
+    ///     .action_id("row_actions")
+    ///     .options(vec![Opt::new("Edit", "edit"), Opt::new("Delete", "delete")])
+    ///     .build();
+    /// ```
+    #[derive(Debug)]
+    pub struct OverflowMenuBuilder<'a, A, O> {
+        action_id: Option<Cow<'a, str>>,
+        options: Option<Vec<Opt>>,
+        confirm: Option<Confirm>,
+        state: std::marker::PhantomData<(A, O)>,
+    }
+
+    impl<'a, A, O> OverflowMenuBuilder<'a, A, O> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            OverflowMenuBuilder {
+                action_id: None,
+                options: None,
+                confirm: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        ///
+        /// An identifier for this action, used to identify the source of
+        /// interaction payloads. Must be unique within a block.
+        /// Maximum length for this field is 255 characters.
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> OverflowMenuBuilder<'a, Set<method::action_id>, O> {
+            OverflowMenuBuilder {
+                action_id: Some(action_id.into()),
+                options: self.options,
+                confirm: self.confirm,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `options` (**Required**)
+        ///
+        /// An array of [option objects 🔗] to display in the menu.
+        /// Maximum of 5 options; each may set its own `url` to
+        /// open when clicked.
+        ///
+        /// [option objects 🔗]: https://api.slack.com/reference/block-kit/composition-objects#option
+        pub fn options(
+            self,
+            options: impl IntoIterator<Item = impl Into<Opt>>,
+        ) -> OverflowMenuBuilder<'a, A, Set<method::options>> {
+            OverflowMenuBuilder {
+                action_id: self.action_id,
+                options: Some(options.into_iter().map(Into::into).collect()),
+                confirm: self.confirm,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user chooses an
+        /// option, e.g. "Are you sure you want to delete this row?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+    }
+
+    impl<'a> OverflowMenuBuilder<'a, Set<method::action_id>, Set<method::options>> {
+        /// All done building, now give me an overflow menu!
+        ///
+        /// > `no method name 'build' found for struct 'OverflowMenuBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `OverflowMenuBuilder`.
+        pub fn build(self) -> OverflowMenu<'a> {
+            OverflowMenu {
+                action_id: self.action_id.unwrap(),
+                options: self.options.unwrap(),
+                confirm: self.confirm,
+            }
+        }
+    }
+}
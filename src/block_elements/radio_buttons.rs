@@ -0,0 +1,233 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::{Confirm, Opt};
+use crate::val_helpr::ValidationResult;
+
+/// # Radio Button Group
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements#radio)
+///
+/// Works with the following block types: Section, Actions, Input
+///
+/// A radio button group that allows a user to choose one item from a
+/// list of possible options.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+#[validate(schema(function = "validation::initial_option_subset_of_options"))]
+pub struct RadioButtons<'a> {
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[validate]
+    #[validate(length(max = 10))]
+    options: Vec<Opt>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    initial_option: Option<Opt>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> RadioButtons<'a> {
+    /// Build a new radio button group.
+    ///
+    /// # Example
+    /// see example for `build::RadioButtonsBuilder`.
+    pub fn builder() -> build::RadioButtonsBuilderInit<'a> {
+        build::RadioButtonsBuilderInit::new()
+    }
+
+    /// Validate that this radio button group agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `options` has more than 10 elements
+    /// - If any contained `Opt` is itself invalid
+    /// - If `initial_option` is not present in `options`
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+
+    pub(crate) fn action_id(&self) -> &str {
+        &self.action_id
+    }
+
+    pub(crate) fn options(&self) -> &[Opt] {
+        &self.options
+    }
+
+    pub(crate) fn initial_option(&self) -> Option<&Opt> {
+        self.initial_option.as_ref()
+    }
+
+    pub(crate) fn confirm(&self) -> Option<&Confirm> {
+        self.confirm.as_ref()
+    }
+}
+
+mod validation {
+    use crate::val_helpr::{is_subset, ValidatorResult};
+
+    use super::RadioButtons;
+
+    pub fn initial_option_subset_of_options(rb: &RadioButtons) -> ValidatorResult {
+        match &rb.initial_option {
+            None => Ok(()),
+            Some(initial) => is_subset("initial_option", std::iter::once(initial), &rb.options),
+        }
+    }
+}
+
+/// Radio button group builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// RadioButtonsBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+        /// RadioButtonsBuilder.options
+        #[derive(Copy, Clone, Debug)]
+        pub struct options;
+    }
+
+    /// Initial state for the RadioButtons builder
+    pub type RadioButtonsBuilderInit<'a> = RadioButtonsBuilder<
+        'a,
+        RequiredMethodNotCalled<method::action_id>,
+        RequiredMethodNotCalled<method::options>,
+    >;
+
+    /// Radio button group builder
+    ///
+    /// # Required Methods
+    /// `RadioButtonsBuilder::build()` is only available if these methods have been called:
+    ///  - `action_id`
+    ///  - `options`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::RadioButtons;
+    /// use slack_blocks::compose::Opt;
+    ///
+    /// let radios = RadioButtons::builder()
+    ///     .action_id("favorite_color")
+    ///     .options(vec![Opt::new("Red", "red")])
+    ///     .build();
+    /// ```
+    #[derive(Debug)]
+    pub struct RadioButtonsBuilder<'a, A, O> {
+        action_id: Option<Cow<'a, str>>,
+        options: Option<Vec<Opt>>,
+        initial_option: Option<Opt>,
+        confirm: Option<Confirm>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<(A, O)>,
+    }
+
+    impl<'a, A, O> RadioButtonsBuilder<'a, A, O> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            RadioButtonsBuilder {
+                action_id: None,
+                options: None,
+                initial_option: None,
+                confirm: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        ///
+        /// An identifier for this action, used to identify the source of
+        /// interaction payloads. Must be unique within a block.
+        /// Maximum length for this field is 255 characters.
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> RadioButtonsBuilder<'a, Set<method::action_id>, O> {
+            RadioButtonsBuilder {
+                action_id: Some(action_id.into()),
+                options: self.options,
+                initial_option: self.initial_option,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `options` (**Required**)
+        ///
+        /// An array of [option objects 🔗]. Maximum of 10 options.
+        ///
+        /// [option objects 🔗]: https://api.slack.com/reference/block-kit/composition-objects#option
+        pub fn options(
+            self,
+            options: impl IntoIterator<Item = impl Into<Opt>>,
+        ) -> RadioButtonsBuilder<'a, A, Set<method::options>> {
+            RadioButtonsBuilder {
+                action_id: self.action_id,
+                options: Some(options.into_iter().map(Into::into).collect()),
+                initial_option: self.initial_option,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `initial_option` (Optional)
+        ///
+        /// One of `options` to pre-select when the radio group is first
+        /// rendered.
+        pub fn initial_option(mut self, initial_option: Opt) -> Self {
+            self.initial_option = Some(initial_option);
+            self
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user changes their
+        /// selection, e.g. "Are you sure you want to pick this option?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this radio button group should be focused as soon as
+        /// the containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> RadioButtonsBuilder<'a, Set<method::action_id>, Set<method::options>> {
+        /// All done building, now give me a radio button group!
+        ///
+        /// > `no method name 'build' found for struct 'RadioButtonsBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `RadioButtonsBuilder`.
+        pub fn build(self) -> RadioButtons<'a> {
+            RadioButtons {
+                action_id: self.action_id.unwrap(),
+                options: self.options.unwrap(),
+                initial_option: self.initial_option,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
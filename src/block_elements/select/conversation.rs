@@ -0,0 +1,325 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::Confirm;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// ## Select menu with conversations list
+/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#conversation_select)
+///
+/// This select menu will populate its options with a list of public and private channels,
+/// DMs, and MPIMs visible to the current user in the active workspace.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+#[validate(schema(function = "validation::initial_conversation_xor_default_to_current"))]
+pub struct Conversation<'a> {
+    placeholder: text::Plain,
+
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_conversation: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_to_current_conversation: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_url_enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    filter: Option<ConversationFilter>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> Conversation<'a> {
+    /// Build a new conversation select menu.
+    ///
+    /// # Example
+    /// see example for `build::ConversationBuilder`.
+    pub fn builder() -> build::ConversationBuilderInit<'a> {
+        build::ConversationBuilderInit::new()
+    }
+
+    /// Validate that this conversation select menu agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `confirm` is set and invalid
+    /// - If both `initial_conversation` and `default_to_current_conversation` are set
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+
+    /// Whether this select was configured (via `response_url_enabled`) to send
+    /// a response_url along with its interaction payload.
+    ///
+    /// Slack only honors `response_url_enabled` for selects living in an
+    /// `input` block - used by `actions::Contents::validate` to reject
+    /// selects that request it outside of that context.
+    pub(crate) fn response_url_enabled(&self) -> bool {
+        self.response_url_enabled.unwrap_or(false)
+    }
+}
+
+/// Controls which kinds of conversations populate a `Conversation` select's list.
+///
+/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#conversation_select)
+#[derive(Clone, Debug, Default, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct ConversationFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include: Option<Vec<ConversationKind>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_external_shared_channels: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_bot_users: Option<bool>,
+}
+
+impl ConversationFilter {
+    /// Create a filter with no restrictions. Use the `with_*` methods to narrow it down.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Restrict the list to only the given kinds of conversation.
+    pub fn with_include(mut self, include: impl IntoIterator<Item = ConversationKind>) -> Self {
+        self.include = Some(include.into_iter().collect());
+        self
+    }
+
+    /// Exclude shared channels with external organizations from the list.
+    pub fn with_exclude_external_shared_channels(mut self, exclude: bool) -> Self {
+        self.exclude_external_shared_channels = Some(exclude);
+        self
+    }
+
+    /// Exclude bot users from the list.
+    pub fn with_exclude_bot_users(mut self, exclude: bool) -> Self {
+        self.exclude_bot_users = Some(exclude);
+        self
+    }
+
+    /// Validate that this filter agrees with Slack's model requirements
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+mod validation {
+    use super::Conversation;
+    use crate::val_helpr::{error, ValidatorResult};
+
+    pub fn initial_conversation_xor_default_to_current(select: &Conversation) -> ValidatorResult {
+        if select.initial_conversation.is_some() && select.default_to_current_conversation.is_some() {
+            Err(error(
+                "initial_conversation_xor_default_to_current",
+                "Conversation select may only set one of `initial_conversation` or \
+                 `default_to_current_conversation`, not both",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The kinds of conversation a `ConversationFilter` can include.
+#[derive(Copy, Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationKind {
+    Im,
+    Mpim,
+    Private,
+    Public,
+}
+
+/// Conversation select menu builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// ConversationBuilder.placeholder
+        #[derive(Copy, Clone, Debug)]
+        pub struct placeholder;
+        /// ConversationBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the Conversation select builder
+    pub type ConversationBuilderInit<'a> = ConversationBuilder<
+        'a,
+        RequiredMethodNotCalled<method::placeholder>,
+        RequiredMethodNotCalled<method::action_id>,
+    >;
+
+    /// Conversation select menu builder
+    ///
+    /// # Required Methods
+    /// `ConversationBuilder::build()` is only available if these methods have been called:
+    ///  - `placeholder`
+    ///  - `action_id`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::select::Conversation;
+    ///
+    /// let select = Conversation::builder()
+    ///     .placeholder("Choose a conversation")
+    ///     .action_id("convo_picker")
+    ///     .build();
+    /// ```
+    #[derive(Debug)]
+    pub struct ConversationBuilder<'a, P, A> {
+        placeholder: Option<text::Plain>,
+        action_id: Option<Cow<'a, str>>,
+        confirm: Option<Confirm>,
+        initial_conversation: Option<String>,
+        default_to_current_conversation: Option<bool>,
+        response_url_enabled: Option<bool>,
+        filter: Option<ConversationFilter>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<(P, A)>,
+    }
+
+    impl<'a, P, A> ConversationBuilder<'a, P, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            ConversationBuilder {
+                placeholder: None,
+                action_id: None,
+                confirm: None,
+                initial_conversation: None,
+                default_to_current_conversation: None,
+                response_url_enabled: None,
+                filter: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (**Required**)
+        pub fn placeholder(
+            self,
+            placeholder: impl Into<text::Plain>,
+        ) -> ConversationBuilder<'a, Set<method::placeholder>, A> {
+            ConversationBuilder {
+                placeholder: Some(placeholder.into()),
+                action_id: self.action_id,
+                confirm: self.confirm,
+                initial_conversation: self.initial_conversation,
+                default_to_current_conversation: self.default_to_current_conversation,
+                response_url_enabled: self.response_url_enabled,
+                filter: self.filter,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> ConversationBuilder<'a, P, Set<method::action_id>> {
+            ConversationBuilder {
+                placeholder: self.placeholder,
+                action_id: Some(action_id.into()),
+                confirm: self.confirm,
+                initial_conversation: self.initial_conversation,
+                default_to_current_conversation: self.default_to_current_conversation,
+                response_url_enabled: self.response_url_enabled,
+                filter: self.filter,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user chooses an
+        /// option, e.g. "Are you sure you want to delete this channel?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `initial_conversation` (Optional)
+        ///
+        /// The ID of any valid conversation to be pre-selected when the menu
+        /// is first rendered. Cannot be used alongside `default_to_current_conversation`.
+        pub fn initial_conversation(mut self, conversation_id: impl ToString) -> Self {
+            self.initial_conversation = Some(conversation_id.to_string());
+            self
+        }
+
+        /// Set `default_to_current_conversation` (Optional)
+        ///
+        /// Pre-select the conversation that the user is currently viewing when
+        /// the menu is first rendered, if possible.
+        pub fn default_to_current_conversation(mut self, default: bool) -> Self {
+            self.default_to_current_conversation = Some(default);
+            self
+        }
+
+        /// Set `response_url_enabled` (Optional)
+        ///
+        /// When `true`, the interaction payload sent when a user changes this
+        /// select's value will contain a `response_url`.
+        ///
+        /// Only valid for selects inside an `input` block - an `actions` block
+        /// containing a select with this set will fail `validate`.
+        pub fn response_url_enabled(mut self, enabled: bool) -> Self {
+            self.response_url_enabled = Some(enabled);
+            self
+        }
+
+        /// Set `filter` (Optional)
+        ///
+        /// Narrow down the kinds of conversations that populate this select's list.
+        pub fn filter(mut self, filter: ConversationFilter) -> Self {
+            self.filter = Some(filter);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this select menu should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> ConversationBuilder<'a, Set<method::placeholder>, Set<method::action_id>> {
+        /// All done building, now give me a select menu!
+        ///
+        /// > `no method name 'build' found for struct 'ConversationBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `ConversationBuilder`.
+        pub fn build(self) -> Conversation<'a> {
+            Conversation {
+                placeholder: self.placeholder.unwrap(),
+                action_id: self.action_id.unwrap(),
+                confirm: self.confirm,
+                initial_conversation: self.initial_conversation,
+                default_to_current_conversation: self.default_to_current_conversation,
+                response_url_enabled: self.response_url_enabled,
+                filter: self.filter,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
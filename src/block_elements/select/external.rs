@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::Confirm;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// ## Select menu with external data source
+/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#external_select)
+///
+/// This select menu will load its options from an external data source,
+/// allowing for a dynamic list of options.
+///
+/// ### Setup
+/// For a guide to set up your app to use this element type, go to the Slack
+/// API section for [Select menu with external data source 🔗].
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct External<'a> {
+    placeholder: text::Plain,
+
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> External<'a> {
+    /// Build a new external select menu.
+    ///
+    /// # Example
+    /// see example for `build::ExternalBuilder`.
+    pub fn builder() -> build::ExternalBuilderInit<'a> {
+        build::ExternalBuilderInit::new()
+    }
+
+    /// Validate that this external select menu agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+/// External select menu builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// ExternalBuilder.placeholder
+        #[derive(Copy, Clone, Debug)]
+        pub struct placeholder;
+        /// ExternalBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the External select builder
+    pub type ExternalBuilderInit<'a> = ExternalBuilder<
+        'a,
+        RequiredMethodNotCalled<method::placeholder>,
+        RequiredMethodNotCalled<method::action_id>,
+    >;
+
+    /// External select menu builder
+    ///
+    /// # Required Methods
+    /// `ExternalBuilder::build()` is only available if these methods have been called:
+    ///  - `placeholder`
+    ///  - `action_id`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::select::External;
+    ///
+    /// let select = External::builder()
+    ///     .placeholder("Choose a state")
+    ///     .action_id("state_picker")
+    ///     .build();
+    /// ```
+    #[derive(Debug)]
+    pub struct ExternalBuilder<'a, P, A> {
+        placeholder: Option<text::Plain>,
+        action_id: Option<Cow<'a, str>>,
+        confirm: Option<Confirm>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<(P, A)>,
+    }
+
+    impl<'a, P, A> ExternalBuilder<'a, P, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            ExternalBuilder {
+                placeholder: None,
+                action_id: None,
+                confirm: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (**Required**)
+        pub fn placeholder(
+            self,
+            placeholder: impl Into<text::Plain>,
+        ) -> ExternalBuilder<'a, Set<method::placeholder>, A> {
+            ExternalBuilder {
+                placeholder: Some(placeholder.into()),
+                action_id: self.action_id,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> ExternalBuilder<'a, P, Set<method::action_id>> {
+            ExternalBuilder {
+                placeholder: self.placeholder,
+                action_id: Some(action_id.into()),
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user chooses an
+        /// option, e.g. "Are you sure you want to delete this channel?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this select menu should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> ExternalBuilder<'a, Set<method::placeholder>, Set<method::action_id>> {
+        /// All done building, now give me a select menu!
+        ///
+        /// > `no method name 'build' found for struct 'ExternalBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `ExternalBuilder`.
+        pub fn build(self) -> External<'a> {
+            External {
+                placeholder: self.placeholder.unwrap(),
+                action_id: self.action_id.unwrap(),
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
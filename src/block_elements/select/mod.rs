@@ -1,17 +1,27 @@
 use serde::{Deserialize, Serialize};
-use std::borrow::Cow;
 
-use crate::text;
 use crate::convert;
 
-mod builder;
-pub use builder::SelectBuilder;
+mod static_select;
+pub use static_select::Static;
+
+mod external;
+pub use external::External;
+
+mod user;
+pub use user::User;
+
+mod conversation;
+pub use conversation::{Conversation, ConversationFilter, ConversationKind};
 
 mod public_channel;
 pub use public_channel::PublicChannel;
 
+pub mod multi;
+pub use multi::Multi;
+
 mod select_ty_value {
-    pub const PUBLIC_CHANNEL: &'static str = "users_select";
+    pub const PUBLIC_CHANNEL: &'static str = "channels_select";
 }
 
 /// # Select Menu Element
@@ -29,64 +39,24 @@ mod select_ty_value {
 /// [guide to enabling interactivity 🔗]: https://api.slack.com/interactivity/handling
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
 pub enum Select<'a> {
-    Static(Static),
-    External(External),
-    User(User),
-    Conversation(Conversation),
-    #[serde(rename = "channels_select")]
-    PublicChannel(PublicChannel<'a>),
-}
-
-impl<'a> Select<'a> {
-    pub fn from_placeholder_and_action_id(
-        placeholder: impl Into<text::Plain>,
-        action_id: impl Into<Cow<'a, str>>
-    ) -> SelectBuilder<'a> {
-        SelectBuilder::from_placeholder_and_action_id(placeholder, action_id)
-    }
-}
-
-convert!(impl From<User> for Select<'static> => |u| Select::User(u));
-convert!(impl From<Static> for Select<'static> => |s| Select::Static(s));
-convert!(impl From<External> for Select<'static> => |e| Select::External(e));
-convert!(impl From<Conversation> for Select<'static> => |e| Select::Conversation(e));
-convert!(impl<'_> From<PublicChannel> for Select => |e| Select::PublicChannel(e));
+    #[serde(rename = "static_select")]
+    Static(Static<'a>),
 
-/// ## Select menu with static options
-/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#static_select)
-///
-/// This is the simplest form of select menu,
-/// with a static list of options passed in when defining the element.
-///
-#[derive(Clone, Default, Debug, Deserialize, Hash, PartialEq, Serialize)]
-pub struct Static {}
+    #[serde(rename = "external_select")]
+    External(External<'a>),
 
-/// ## Select menu with external data source
-/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#external_select)
-///
-/// This select menu will load its options from an external data source,
-/// allowing for a dynamic list of options.
-///
-/// ### Setup
-/// For a guide to set up your app to use this element type, go to the Slack
-/// API section for [Select menu with external data source 🔗].
-///
-#[derive(Clone, Default, Debug, Deserialize, Hash, PartialEq, Serialize)]
-pub struct External {}
+    #[serde(rename = "users_select")]
+    User(User<'a>),
 
-/// ## Select menu with user list
-/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#users_select)
-///
-/// This select menu will populate its options with a list of
-/// Slack users visible to the current user in the active workspace.
-#[derive(Clone, Default, Debug, Deserialize, Hash, PartialEq, Serialize)]
-pub struct User {}
+    #[serde(rename = "conversations_select")]
+    Conversation(Conversation<'a>),
 
-/// ## Select menu with conversations list
-/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#conversation_select)
-///
-/// This select menu will populate its options with a list of public and private channels,
-/// DMs, and MPIMs visible to the current user in the active workspace.
-#[derive(Clone, Default, Debug, Deserialize, Hash, PartialEq, Serialize)]
-pub struct Conversation {}
+    #[serde(rename = "channels_select")]
+    PublicChannel(PublicChannel<'a>),
+}
 
+convert!(impl<'_> From<Static> for Select => |s| Select::Static(s));
+convert!(impl<'_> From<External> for Select => |s| Select::External(s));
+convert!(impl<'_> From<User> for Select => |s| Select::User(s));
+convert!(impl<'_> From<Conversation> for Select => |s| Select::Conversation(s));
+convert!(impl<'_> From<PublicChannel> for Select => |s| Select::PublicChannel(s));
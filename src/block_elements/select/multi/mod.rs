@@ -0,0 +1,56 @@
+//! # Multi-Select Menu Elements
+//!
+//! [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements#multi_select)
+//!
+//! A multi-select menu allows a user to select multiple items from a list of options.
+//! Just like regular [select menus 🔗](super), multi-select menus also include type-ahead
+//! functionality, where a user can type a part or all of an option string to filter the list.
+//!
+//! Slack has a multi-select counterpart for every single-select data source: static,
+//! external, users, conversations, and channels.
+
+use serde::{Deserialize, Serialize};
+
+use crate::convert;
+
+mod multi_static;
+pub use multi_static::MultiStatic;
+
+mod multi_external;
+pub use multi_external::MultiExternal;
+
+mod multi_user;
+pub use multi_user::MultiUser;
+
+mod multi_conversation;
+pub use multi_conversation::MultiConversation;
+
+mod multi_public_channel;
+pub use multi_public_channel::MultiPublicChannel;
+
+/// # Multi-Select Menu Element
+///
+/// Mirrors `select::Select`, but for the multi-select variants of each data source.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub enum Multi<'a> {
+    #[serde(rename = "multi_static_select")]
+    Static(MultiStatic<'a>),
+
+    #[serde(rename = "multi_external_select")]
+    External(MultiExternal<'a>),
+
+    #[serde(rename = "multi_users_select")]
+    User(MultiUser<'a>),
+
+    #[serde(rename = "multi_conversations_select")]
+    Conversation(MultiConversation<'a>),
+
+    #[serde(rename = "multi_channels_select")]
+    PublicChannel(MultiPublicChannel<'a>),
+}
+
+convert!(impl<'_> From<MultiStatic> for Multi => |s| Multi::Static(s));
+convert!(impl<'_> From<MultiExternal> for Multi => |s| Multi::External(s));
+convert!(impl<'_> From<MultiUser> for Multi => |s| Multi::User(s));
+convert!(impl<'_> From<MultiConversation> for Multi => |s| Multi::Conversation(s));
+convert!(impl<'_> From<MultiPublicChannel> for Multi => |s| Multi::PublicChannel(s));
@@ -0,0 +1,246 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::block_elements::select::ConversationFilter;
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::Confirm;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// ## Multi-select menu with conversations list
+/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#conversation_multi_select)
+///
+/// This select menu will populate its options with a list of public and private channels,
+/// DMs, and MPIMs visible to the current user in the active workspace, and lets them choose multiple.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+#[validate(schema(function = "validation::initial_conversations_xor_default_to_current"))]
+pub struct MultiConversation<'a> {
+    placeholder: text::Plain,
+
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_conversations: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_to_current_conversation: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    max_selected_items: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    filter: Option<ConversationFilter>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> MultiConversation<'a> {
+    /// Build a new conversation multi-select menu.
+    pub fn builder() -> build::MultiConversationBuilderInit<'a> {
+        build::MultiConversationBuilderInit::new()
+    }
+
+    /// Validate that this multi-select menu agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `max_selected_items` is set to 0
+    /// - If `confirm` is set and invalid
+    /// - If both `initial_conversations` and `default_to_current_conversation` are set
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+mod validation {
+    use super::MultiConversation;
+    use crate::val_helpr::{error, ValidatorResult};
+
+    pub fn initial_conversations_xor_default_to_current(
+        select: &MultiConversation,
+    ) -> ValidatorResult {
+        if select.initial_conversations.is_some() && select.default_to_current_conversation.is_some() {
+            Err(error(
+                "initial_conversations_xor_default_to_current",
+                "MultiConversation select may only set one of `initial_conversations` or \
+                 `default_to_current_conversation`, not both",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Conversation multi-select menu builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// MultiConversationBuilder.placeholder
+        #[derive(Copy, Clone, Debug)]
+        pub struct placeholder;
+        /// MultiConversationBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the MultiConversation select builder
+    pub type MultiConversationBuilderInit<'a> = MultiConversationBuilder<
+        'a,
+        RequiredMethodNotCalled<method::placeholder>,
+        RequiredMethodNotCalled<method::action_id>,
+    >;
+
+    /// Conversation multi-select menu builder
+    #[derive(Debug)]
+    pub struct MultiConversationBuilder<'a, P, A> {
+        placeholder: Option<text::Plain>,
+        action_id: Option<Cow<'a, str>>,
+        initial_conversations: Option<Vec<String>>,
+        default_to_current_conversation: Option<bool>,
+        max_selected_items: Option<u32>,
+        confirm: Option<Confirm>,
+        filter: Option<ConversationFilter>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<(P, A)>,
+    }
+
+    impl<'a, P, A> MultiConversationBuilder<'a, P, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            MultiConversationBuilder {
+                placeholder: None,
+                action_id: None,
+                initial_conversations: None,
+                default_to_current_conversation: None,
+                max_selected_items: None,
+                confirm: None,
+                filter: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (**Required**)
+        pub fn placeholder(
+            self,
+            placeholder: impl Into<text::Plain>,
+        ) -> MultiConversationBuilder<'a, Set<method::placeholder>, A> {
+            MultiConversationBuilder {
+                placeholder: Some(placeholder.into()),
+                action_id: self.action_id,
+                initial_conversations: self.initial_conversations,
+                default_to_current_conversation: self.default_to_current_conversation,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                filter: self.filter,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> MultiConversationBuilder<'a, P, Set<method::action_id>> {
+            MultiConversationBuilder {
+                placeholder: self.placeholder,
+                action_id: Some(action_id.into()),
+                initial_conversations: self.initial_conversations,
+                default_to_current_conversation: self.default_to_current_conversation,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                filter: self.filter,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `initial_conversations` (Optional)
+        ///
+        /// The IDs of any valid conversations to be pre-selected when the menu
+        /// is first rendered. Cannot be used alongside `default_to_current_conversation`.
+        pub fn initial_conversations(
+            mut self,
+            initial_conversations: impl IntoIterator<Item = impl ToString>,
+        ) -> Self {
+            self.initial_conversations = Some(
+                initial_conversations
+                    .into_iter()
+                    .map(|c| c.to_string())
+                    .collect(),
+            );
+            self
+        }
+
+        /// Set `default_to_current_conversation` (Optional)
+        ///
+        /// Pre-select the conversation that the user is currently viewing when
+        /// the menu is first rendered, if possible.
+        pub fn default_to_current_conversation(mut self, default: bool) -> Self {
+            self.default_to_current_conversation = Some(default);
+            self
+        }
+
+        /// Set `max_selected_items` (Optional)
+        pub fn max_selected_items(mut self, max: u32) -> Self {
+            self.max_selected_items = Some(max);
+            self
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user chooses an
+        /// option, e.g. "Are you sure you want to delete this channel?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `filter` (Optional)
+        ///
+        /// Narrow down the kinds of conversations that populate this select's list.
+        pub fn filter(mut self, filter: ConversationFilter) -> Self {
+            self.filter = Some(filter);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this select menu should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> MultiConversationBuilder<'a, Set<method::placeholder>, Set<method::action_id>> {
+        /// All done building, now give me a darn multi-select menu!
+        pub fn build(self) -> MultiConversation<'a> {
+            MultiConversation {
+                placeholder: self.placeholder.unwrap(),
+                action_id: self.action_id.unwrap(),
+                initial_conversations: self.initial_conversations,
+                default_to_current_conversation: self.default_to_current_conversation,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                filter: self.filter,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
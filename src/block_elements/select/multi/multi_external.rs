@@ -0,0 +1,183 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::{Confirm, Opt};
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// ## Multi-select menu with external data source
+/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#external_multi_select)
+///
+/// This select menu will load its options from an external data source,
+/// allowing for a dynamic list of options, and lets a user choose multiple items.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct MultiExternal<'a> {
+    placeholder: text::Plain,
+
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    initial_options: Option<Vec<Opt>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    max_selected_items: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> MultiExternal<'a> {
+    /// Build a new external multi-select menu.
+    pub fn builder() -> build::MultiExternalBuilderInit<'a> {
+        build::MultiExternalBuilderInit::new()
+    }
+
+    /// Validate that this multi-select menu agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `max_selected_items` is set to 0
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+/// External multi-select menu builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// MultiExternalBuilder.placeholder
+        #[derive(Copy, Clone, Debug)]
+        pub struct placeholder;
+        /// MultiExternalBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the MultiExternal select builder
+    pub type MultiExternalBuilderInit<'a> = MultiExternalBuilder<
+        'a,
+        RequiredMethodNotCalled<method::placeholder>,
+        RequiredMethodNotCalled<method::action_id>,
+    >;
+
+    /// External multi-select menu builder
+    #[derive(Debug)]
+    pub struct MultiExternalBuilder<'a, P, A> {
+        placeholder: Option<text::Plain>,
+        action_id: Option<Cow<'a, str>>,
+        initial_options: Option<Vec<Opt>>,
+        max_selected_items: Option<u32>,
+        confirm: Option<Confirm>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<(P, A)>,
+    }
+
+    impl<'a, P, A> MultiExternalBuilder<'a, P, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            MultiExternalBuilder {
+                placeholder: None,
+                action_id: None,
+                initial_options: None,
+                max_selected_items: None,
+                confirm: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (**Required**)
+        pub fn placeholder(
+            self,
+            placeholder: impl Into<text::Plain>,
+        ) -> MultiExternalBuilder<'a, Set<method::placeholder>, A> {
+            MultiExternalBuilder {
+                placeholder: Some(placeholder.into()),
+                action_id: self.action_id,
+                initial_options: self.initial_options,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> MultiExternalBuilder<'a, P, Set<method::action_id>> {
+            MultiExternalBuilder {
+                placeholder: self.placeholder,
+                action_id: Some(action_id.into()),
+                initial_options: self.initial_options,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `initial_options` (Optional)
+        pub fn initial_options(
+            mut self,
+            initial_options: impl IntoIterator<Item = impl Into<Opt>>,
+        ) -> Self {
+            self.initial_options = Some(initial_options.into_iter().map(Into::into).collect());
+            self
+        }
+
+        /// Set `max_selected_items` (Optional)
+        pub fn max_selected_items(mut self, max: u32) -> Self {
+            self.max_selected_items = Some(max);
+            self
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user chooses an
+        /// option, e.g. "Are you sure you want to delete this channel?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this select menu should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> MultiExternalBuilder<'a, Set<method::placeholder>, Set<method::action_id>> {
+        /// All done building, now give me a darn multi-select menu!
+        pub fn build(self) -> MultiExternal<'a> {
+            MultiExternal {
+                placeholder: self.placeholder.unwrap(),
+                action_id: self.action_id.unwrap(),
+                initial_options: self.initial_options,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::{Confirm, Opt, OptGroup};
+use crate::text;
+use crate::val_helpr::{error, ValidationResult, ValidatorResult};
+
+/// ## Multi-select menu with static options
+/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#static_multi_select)
+///
+/// This is the simplest form of select menu, with a static list of options
+/// passed in when defining the element, that allows a user to pick multiple items.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+#[validate(schema(function = "validation::options_xor_option_groups"))]
+pub struct MultiStatic<'a> {
+    placeholder: text::Plain,
+
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 100))]
+    #[validate]
+    options: Option<Vec<Opt>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 100))]
+    #[validate]
+    option_groups: Option<Vec<OptGroup>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    initial_options: Option<Vec<Opt>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    max_selected_items: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> MultiStatic<'a> {
+    /// Build a new static multi-select menu.
+    ///
+    /// # Example
+    /// see example for `build::MultiStaticBuilder`.
+    pub fn builder() -> build::MultiStaticBuilderInit<'a> {
+        build::MultiStaticBuilderInit::new()
+    }
+
+    /// Validate that this multi-select menu agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If both `options` and `option_groups` are set, or neither are
+    /// - If `max_selected_items` is set to 0
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+
+    pub(crate) fn action_id(&self) -> &str {
+        &self.action_id
+    }
+
+    pub(crate) fn options(&self) -> Option<&[Opt]> {
+        self.options.as_deref()
+    }
+
+    pub(crate) fn option_groups(&self) -> Option<&[OptGroup]> {
+        self.option_groups.as_deref()
+    }
+
+    pub(crate) fn initial_options(&self) -> Option<&[Opt]> {
+        self.initial_options.as_deref()
+    }
+
+    pub(crate) fn confirm(&self) -> Option<&Confirm> {
+        self.confirm.as_ref()
+    }
+}
+
+mod validation {
+    use super::*;
+
+    pub fn options_xor_option_groups(select: &MultiStatic) -> ValidatorResult {
+        match (&select.options, &select.option_groups) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            (Some(_), Some(_)) => Err(error(
+                "options_xor_option_groups",
+                "MultiStatic select may only set one of `options` or `option_groups`, not both",
+            )),
+            (None, None) => Err(error(
+                "options_xor_option_groups",
+                "MultiStatic select must set one of `options` or `option_groups`",
+            )),
+        }
+    }
+}
+
+/// Static multi-select menu builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// MultiStaticBuilder.placeholder
+        #[derive(Copy, Clone, Debug)]
+        pub struct placeholder;
+        /// MultiStaticBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the MultiStatic select builder
+    pub type MultiStaticBuilderInit<'a> = MultiStaticBuilder<
+        'a,
+        RequiredMethodNotCalled<method::placeholder>,
+        RequiredMethodNotCalled<method::action_id>,
+    >;
+
+    /// Static multi-select menu builder
+    ///
+    /// # Required Methods
+    /// `MultiStaticBuilder::build()` is only available if these methods have been called:
+    ///  - `placeholder`
+    ///  - `action_id`
+    ///
+    /// `options` and `option_groups` are not gated by the type-state above:
+    /// exactly one of them must be set, but that is only checked by
+    /// `validate()` at runtime, not enforced at compile time.
+    #[derive(Debug)]
+    pub struct MultiStaticBuilder<'a, P, A> {
+        placeholder: Option<text::Plain>,
+        action_id: Option<Cow<'a, str>>,
+        options: Option<Vec<Opt>>,
+        option_groups: Option<Vec<OptGroup>>,
+        initial_options: Option<Vec<Opt>>,
+        max_selected_items: Option<u32>,
+        confirm: Option<Confirm>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<(P, A)>,
+    }
+
+    impl<'a, P, A> MultiStaticBuilder<'a, P, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            MultiStaticBuilder {
+                placeholder: None,
+                action_id: None,
+                options: None,
+                option_groups: None,
+                initial_options: None,
+                max_selected_items: None,
+                confirm: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (**Required**)
+        pub fn placeholder(
+            self,
+            placeholder: impl Into<text::Plain>,
+        ) -> MultiStaticBuilder<'a, Set<method::placeholder>, A> {
+            MultiStaticBuilder {
+                placeholder: Some(placeholder.into()),
+                action_id: self.action_id,
+                options: self.options,
+                option_groups: self.option_groups,
+                initial_options: self.initial_options,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> MultiStaticBuilder<'a, P, Set<method::action_id>> {
+            MultiStaticBuilder {
+                placeholder: self.placeholder,
+                action_id: Some(action_id.into()),
+                options: self.options,
+                option_groups: self.option_groups,
+                initial_options: self.initial_options,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `options` (Required, unless `option_groups` is set)
+        pub fn options(mut self, options: impl IntoIterator<Item = impl Into<Opt>>) -> Self {
+            self.options = Some(options.into_iter().map(Into::into).collect());
+            self
+        }
+
+        /// Set `option_groups` (Required, unless `options` is set)
+        pub fn option_groups(mut self, option_groups: impl IntoIterator<Item = OptGroup>) -> Self {
+            self.option_groups = Some(option_groups.into_iter().collect());
+            self
+        }
+
+        /// Set `initial_options` (Optional)
+        ///
+        /// One or more of `options` (or nested in `option_groups`) that should
+        /// appear selected when this menu is first rendered.
+        pub fn initial_options(
+            mut self,
+            initial_options: impl IntoIterator<Item = impl Into<Opt>>,
+        ) -> Self {
+            self.initial_options = Some(initial_options.into_iter().map(Into::into).collect());
+            self
+        }
+
+        /// Set `max_selected_items` (Optional)
+        ///
+        /// Caps the number of items a user can select. Must be at least 1.
+        pub fn max_selected_items(mut self, max: u32) -> Self {
+            self.max_selected_items = Some(max);
+            self
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user chooses an
+        /// option, e.g. "Are you sure you want to delete this channel?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this select menu should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> MultiStaticBuilder<'a, Set<method::placeholder>, Set<method::action_id>> {
+        /// All done building, now give me a darn multi-select menu!
+        pub fn build(self) -> MultiStatic<'a> {
+            MultiStatic {
+                placeholder: self.placeholder.unwrap(),
+                action_id: self.action_id.unwrap(),
+                options: self.options,
+                option_groups: self.option_groups,
+                initial_options: self.initial_options,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::Confirm;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// ## Multi-select menu with user list
+/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#users_multi_select)
+///
+/// This select menu will populate its options with a list of
+/// Slack users visible to the current user in the active workspace,
+/// and lets them choose multiple.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct MultiUser<'a> {
+    placeholder: text::Plain,
+
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_users: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    max_selected_items: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> MultiUser<'a> {
+    /// Build a new user multi-select menu.
+    pub fn builder() -> build::MultiUserBuilderInit<'a> {
+        build::MultiUserBuilderInit::new()
+    }
+
+    /// Validate that this multi-select menu agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `max_selected_items` is set to 0
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+/// User multi-select menu builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// MultiUserBuilder.placeholder
+        #[derive(Copy, Clone, Debug)]
+        pub struct placeholder;
+        /// MultiUserBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the MultiUser select builder
+    pub type MultiUserBuilderInit<'a> = MultiUserBuilder<
+        'a,
+        RequiredMethodNotCalled<method::placeholder>,
+        RequiredMethodNotCalled<method::action_id>,
+    >;
+
+    /// User multi-select menu builder
+    #[derive(Debug)]
+    pub struct MultiUserBuilder<'a, P, A> {
+        placeholder: Option<text::Plain>,
+        action_id: Option<Cow<'a, str>>,
+        initial_users: Option<Vec<String>>,
+        max_selected_items: Option<u32>,
+        confirm: Option<Confirm>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<(P, A)>,
+    }
+
+    impl<'a, P, A> MultiUserBuilder<'a, P, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            MultiUserBuilder {
+                placeholder: None,
+                action_id: None,
+                initial_users: None,
+                max_selected_items: None,
+                confirm: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (**Required**)
+        pub fn placeholder(
+            self,
+            placeholder: impl Into<text::Plain>,
+        ) -> MultiUserBuilder<'a, Set<method::placeholder>, A> {
+            MultiUserBuilder {
+                placeholder: Some(placeholder.into()),
+                action_id: self.action_id,
+                initial_users: self.initial_users,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> MultiUserBuilder<'a, P, Set<method::action_id>> {
+            MultiUserBuilder {
+                placeholder: self.placeholder,
+                action_id: Some(action_id.into()),
+                initial_users: self.initial_users,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `initial_users` (Optional)
+        pub fn initial_users(
+            mut self,
+            initial_users: impl IntoIterator<Item = impl ToString>,
+        ) -> Self {
+            self.initial_users = Some(initial_users.into_iter().map(|u| u.to_string()).collect());
+            self
+        }
+
+        /// Set `max_selected_items` (Optional)
+        pub fn max_selected_items(mut self, max: u32) -> Self {
+            self.max_selected_items = Some(max);
+            self
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user chooses an
+        /// option, e.g. "Are you sure you want to delete this channel?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this select menu should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> MultiUserBuilder<'a, Set<method::placeholder>, Set<method::action_id>> {
+        /// All done building, now give me a darn multi-select menu!
+        pub fn build(self) -> MultiUser<'a> {
+            MultiUser {
+                placeholder: self.placeholder.unwrap(),
+                action_id: self.action_id.unwrap(),
+                initial_users: self.initial_users,
+                max_selected_items: self.max_selected_items,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
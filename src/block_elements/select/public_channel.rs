@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::Confirm;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// ## Select menu with channels list
+/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#channel_select)
+///
+/// This select menu will populate its options with a list of
+/// public channels visible to the current user in the active workspace.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct PublicChannel<'a> {
+    placeholder: text::Plain,
+
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_channel: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> PublicChannel<'a> {
+    /// Build a new channel select menu.
+    ///
+    /// # Example
+    /// see example for `build::PublicChannelBuilder`.
+    pub fn builder() -> build::PublicChannelBuilderInit<'a> {
+        build::PublicChannelBuilderInit::new()
+    }
+
+    /// Validate that this channel select menu agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+/// Channel select menu builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// PublicChannelBuilder.placeholder
+        #[derive(Copy, Clone, Debug)]
+        pub struct placeholder;
+        /// PublicChannelBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the PublicChannel select builder
+    pub type PublicChannelBuilderInit<'a> = PublicChannelBuilder<
+        'a,
+        RequiredMethodNotCalled<method::placeholder>,
+        RequiredMethodNotCalled<method::action_id>,
+    >;
+
+    /// Channel select menu builder
+    ///
+    /// # Required Methods
+    /// `PublicChannelBuilder::build()` is only available if these methods have been called:
+    ///  - `placeholder`
+    ///  - `action_id`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::select::PublicChannel;
+    ///
+    /// let select = PublicChannel::builder()
+    ///     .placeholder("Choose a channel")
+    ///     .action_id("channel_picker")
+    ///     .build();
+    /// ```
+    #[derive(Debug)]
+    pub struct PublicChannelBuilder<'a, P, A> {
+        placeholder: Option<text::Plain>,
+        action_id: Option<Cow<'a, str>>,
+        confirm: Option<Confirm>,
+        initial_channel: Option<String>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<(P, A)>,
+    }
+
+    impl<'a, P, A> PublicChannelBuilder<'a, P, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            PublicChannelBuilder {
+                placeholder: None,
+                action_id: None,
+                confirm: None,
+                initial_channel: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (**Required**)
+        pub fn placeholder(
+            self,
+            placeholder: impl Into<text::Plain>,
+        ) -> PublicChannelBuilder<'a, Set<method::placeholder>, A> {
+            PublicChannelBuilder {
+                placeholder: Some(placeholder.into()),
+                action_id: self.action_id,
+                confirm: self.confirm,
+                initial_channel: self.initial_channel,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> PublicChannelBuilder<'a, P, Set<method::action_id>> {
+            PublicChannelBuilder {
+                placeholder: self.placeholder,
+                action_id: Some(action_id.into()),
+                confirm: self.confirm,
+                initial_channel: self.initial_channel,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user chooses an
+        /// option, e.g. "Are you sure you want to delete this channel?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `initial_channel` (Optional)
+        ///
+        /// The ID of any valid public channel to be pre-selected when the menu is first rendered.
+        pub fn initial_channel(mut self, channel_id: impl ToString) -> Self {
+            self.initial_channel = Some(channel_id.to_string());
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this select menu should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> PublicChannelBuilder<'a, Set<method::placeholder>, Set<method::action_id>> {
+        /// All done building, now give me a select menu!
+        ///
+        /// > `no method name 'build' found for struct 'PublicChannelBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `PublicChannelBuilder`.
+        pub fn build(self) -> PublicChannel<'a> {
+            PublicChannel {
+                placeholder: self.placeholder.unwrap(),
+                action_id: self.action_id.unwrap(),
+                confirm: self.confirm,
+                initial_channel: self.initial_channel,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::{Confirm, DispatchActionConfig, Opt, OptGroup};
+use crate::text;
+use crate::val_helpr::{error, is_subset, ValidationResult, ValidatorResult};
+
+/// ## Select menu with static options
+/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#static_select)
+///
+/// This is the simplest form of select menu,
+/// with a static list of options passed in when defining the element.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+#[validate(schema(function = "validation::options_xor_option_groups"))]
+#[validate(schema(function = "validation::initial_option_subset_of_options"))]
+pub struct Static<'a> {
+    placeholder: text::Plain,
+
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 100))]
+    #[validate]
+    options: Option<Vec<Opt>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 100))]
+    #[validate]
+    option_groups: Option<Vec<OptGroup>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    initial_option: Option<Opt>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+
+    #[serde(rename = "dispatch_action_configuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    dispatch_action_config: Option<DispatchActionConfig>,
+}
+
+impl<'a> Static<'a> {
+    /// Build a new static select menu.
+    ///
+    /// # Example
+    /// see example for `build::StaticBuilder`.
+    pub fn builder() -> build::StaticBuilderInit<'a> {
+        build::StaticBuilderInit::new()
+    }
+
+    /// Validate that this static select menu agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If both `options` and `option_groups` are set, or neither are
+    /// - If `options` or `option_groups` has more than 100 elements
+    /// - If any contained `Opt`/`OptGroup` is itself invalid
+    /// - If `initial_option` is not present in `options`/`option_groups`
+    /// - If `dispatch_action_config` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+mod validation {
+    use super::*;
+
+    pub fn options_xor_option_groups(select: &Static) -> ValidatorResult {
+        match (&select.options, &select.option_groups) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            (Some(_), Some(_)) => Err(error(
+                "options_xor_option_groups",
+                "Static select may only set one of `options` or `option_groups`, not both",
+            )),
+            (None, None) => Err(error(
+                "options_xor_option_groups",
+                "Static select must set one of `options` or `option_groups`",
+            )),
+        }
+    }
+
+    pub fn initial_option_subset_of_options(select: &Static) -> ValidatorResult {
+        let initial = match &select.initial_option {
+            None => return Ok(()),
+            Some(initial) => initial,
+        };
+
+        let pool = select
+            .options
+            .iter()
+            .flatten()
+            .chain(select.option_groups.iter().flatten().flat_map(|g| g.options()));
+
+        is_subset("initial_option", std::iter::once(initial), pool)
+    }
+}
+
+/// Static select menu builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// StaticBuilder.placeholder
+        #[derive(Copy, Clone, Debug)]
+        pub struct placeholder;
+        /// StaticBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the Static select builder
+    pub type StaticBuilderInit<'a> = StaticBuilder<
+        'a,
+        RequiredMethodNotCalled<method::placeholder>,
+        RequiredMethodNotCalled<method::action_id>,
+    >;
+
+    /// Static select menu builder
+    ///
+    /// Allows you to construct safely, with compile-time checks
+    /// on required setter methods.
+    ///
+    /// # Required Methods
+    /// `StaticBuilder::build()` is only available if these methods have been called:
+    ///  - `placeholder`
+    ///  - `action_id`
+    ///
+    /// `options` and `option_groups` aren't gated by the type-state above -
+    /// exactly one of them must still be set, but that's only checked by
+    /// `validate()` at runtime, not enforced at compile time.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::select::Static;
+    /// use slack_blocks::compose::Opt;
+    ///
+    /// let select = Static::builder()
+    ///     .placeholder("Choose a state")
+    ///     .action_id("state_picker")
+    ///     .options(vec![Opt::new("Arizona", "AZ")])
+    ///     .build();
+    /// ```
+    #[derive(Debug)]
+    pub struct StaticBuilder<'a, P, A> {
+        placeholder: Option<text::Plain>,
+        action_id: Option<Cow<'a, str>>,
+        options: Option<Vec<Opt>>,
+        option_groups: Option<Vec<OptGroup>>,
+        initial_option: Option<Opt>,
+        confirm: Option<Confirm>,
+        focus_on_load: Option<bool>,
+        dispatch_action_config: Option<DispatchActionConfig>,
+        state: std::marker::PhantomData<(P, A)>,
+    }
+
+    impl<'a, P, A> StaticBuilder<'a, P, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            StaticBuilder {
+                placeholder: None,
+                action_id: None,
+                options: None,
+                option_groups: None,
+                initial_option: None,
+                confirm: None,
+                focus_on_load: None,
+                dispatch_action_config: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (**Required**)
+        ///
+        /// A `plain_text` string shown in the menu until an option is selected.
+        pub fn placeholder(
+            self,
+            placeholder: impl Into<text::Plain>,
+        ) -> StaticBuilder<'a, Set<method::placeholder>, A> {
+            StaticBuilder {
+                placeholder: Some(placeholder.into()),
+                action_id: self.action_id,
+                options: self.options,
+                option_groups: self.option_groups,
+                initial_option: self.initial_option,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                dispatch_action_config: self.dispatch_action_config,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        ///
+        /// An identifier for this action, used to identify the source of
+        /// interaction payloads. Must be unique within a block.
+        /// Maximum length for this field is 255 characters.
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> StaticBuilder<'a, P, Set<method::action_id>> {
+            StaticBuilder {
+                placeholder: self.placeholder,
+                action_id: Some(action_id.into()),
+                options: self.options,
+                option_groups: self.option_groups,
+                initial_option: self.initial_option,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                dispatch_action_config: self.dispatch_action_config,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `options` (Required, unless `option_groups` is set)
+        ///
+        /// An array of [option objects 🔗]. Maximum of 100 options.
+        ///
+        /// [option objects 🔗]: https://api.slack.com/reference/block-kit/composition-objects#option
+        pub fn options(mut self, options: impl IntoIterator<Item = impl Into<Opt>>) -> Self {
+            self.options = Some(options.into_iter().map(Into::into).collect());
+            self
+        }
+
+        /// Set `option_groups` (Required, unless `options` is set)
+        ///
+        /// An array of [option group objects 🔗]. Maximum of 100 groups.
+        ///
+        /// [option group objects 🔗]: https://api.slack.com/reference/block-kit/composition-objects#option_group
+        pub fn option_groups(mut self, option_groups: impl IntoIterator<Item = OptGroup>) -> Self {
+            self.option_groups = Some(option_groups.into_iter().collect());
+            self
+        }
+
+        /// Set `initial_option` (Optional)
+        ///
+        /// Pre-select one of `options` (or one nested in `option_groups`) when
+        /// the menu is first rendered.
+        pub fn initial_option(mut self, initial_option: Opt) -> Self {
+            self.initial_option = Some(initial_option);
+            self
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user chooses an
+        /// option, e.g. "Are you sure you want to delete this channel?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this select menu should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+
+        /// Set `dispatch_action_config` (Optional)
+        ///
+        /// Configures the events that cause this select menu to dispatch a
+        /// `block_actions` payload, rather than waiting for the surface to
+        /// be submitted.
+        pub fn dispatch_action_config(mut self, config: DispatchActionConfig) -> Self {
+            self.dispatch_action_config = Some(config);
+            self
+        }
+    }
+
+    impl<'a> StaticBuilder<'a, Set<method::placeholder>, Set<method::action_id>> {
+        /// All done building, now give me a select menu!
+        ///
+        /// > `no method name 'build' found for struct 'StaticBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `StaticBuilder`.
+        ///
+        /// ```compile_fail
+        /// use slack_blocks::block_elements::select::Static;
+        ///
+        /// let foo = Static::builder().build(); // Won't compile!
+        /// ```
+        pub fn build(self) -> Static<'a> {
+            Static {
+                placeholder: self.placeholder.unwrap(),
+                action_id: self.action_id.unwrap(),
+                options: self.options,
+                option_groups: self.option_groups,
+                initial_option: self.initial_option,
+                confirm: self.confirm,
+                focus_on_load: self.focus_on_load,
+                dispatch_action_config: self.dispatch_action_config,
+            }
+        }
+    }
+}
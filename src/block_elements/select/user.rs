@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::Confirm;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// ## Select menu with user list
+/// [slack api docs 🔗](https://api.slack.com/reference/block-kit/block-elements#users_select)
+///
+/// This select menu will populate its options with a list of
+/// Slack users visible to the current user in the active workspace.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct User<'a> {
+    placeholder: text::Plain,
+
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    confirm: Option<Confirm>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_user: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+}
+
+impl<'a> User<'a> {
+    /// Build a new user select menu.
+    ///
+    /// # Example
+    /// see example for `build::UserBuilder`.
+    pub fn builder() -> build::UserBuilderInit<'a> {
+        build::UserBuilderInit::new()
+    }
+
+    /// Validate that this user select menu agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `confirm` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+/// User select menu builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// UserBuilder.placeholder
+        #[derive(Copy, Clone, Debug)]
+        pub struct placeholder;
+        /// UserBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the User select builder
+    pub type UserBuilderInit<'a> = UserBuilder<
+        'a,
+        RequiredMethodNotCalled<method::placeholder>,
+        RequiredMethodNotCalled<method::action_id>,
+    >;
+
+    /// User select menu builder
+    ///
+    /// # Required Methods
+    /// `UserBuilder::build()` is only available if these methods have been called:
+    ///  - `placeholder`
+    ///  - `action_id`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::select::User;
+    ///
+    /// let select = User::builder()
+    ///     .placeholder("Choose a user")
+    ///     .action_id("user_picker")
+    ///     .build();
+    /// ```
+    #[derive(Debug)]
+    pub struct UserBuilder<'a, P, A> {
+        placeholder: Option<text::Plain>,
+        action_id: Option<Cow<'a, str>>,
+        confirm: Option<Confirm>,
+        initial_user: Option<String>,
+        focus_on_load: Option<bool>,
+        state: std::marker::PhantomData<(P, A)>,
+    }
+
+    impl<'a, P, A> UserBuilder<'a, P, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            UserBuilder {
+                placeholder: None,
+                action_id: None,
+                confirm: None,
+                initial_user: None,
+                focus_on_load: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (**Required**)
+        pub fn placeholder(
+            self,
+            placeholder: impl Into<text::Plain>,
+        ) -> UserBuilder<'a, Set<method::placeholder>, A> {
+            UserBuilder {
+                placeholder: Some(placeholder.into()),
+                action_id: self.action_id,
+                confirm: self.confirm,
+                initial_user: self.initial_user,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> UserBuilder<'a, P, Set<method::action_id>> {
+            UserBuilder {
+                placeholder: self.placeholder,
+                action_id: Some(action_id.into()),
+                confirm: self.confirm,
+                initial_user: self.initial_user,
+                focus_on_load: self.focus_on_load,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `confirm` (Optional)
+        ///
+        /// A confirmation dialog that pops up after the user chooses an
+        /// option, e.g. "Are you sure you want to delete this channel?".
+        pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+            self.confirm = Some(confirm);
+            self
+        }
+
+        /// Set `initial_user` (Optional)
+        ///
+        /// The ID of any valid public user to be pre-selected when the menu is first rendered.
+        pub fn initial_user(mut self, user_id: impl ToString) -> Self {
+            self.initial_user = Some(user_id.to_string());
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this select menu should be focused as soon as the
+        /// containing surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+    }
+
+    impl<'a> UserBuilder<'a, Set<method::placeholder>, Set<method::action_id>> {
+        /// All done building, now give me a select menu!
+        ///
+        /// > `no method name 'build' found for struct 'UserBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `UserBuilder`.
+        pub fn build(self) -> User<'a> {
+            User {
+                placeholder: self.placeholder.unwrap(),
+                action_id: self.action_id.unwrap(),
+                confirm: self.confirm,
+                initial_user: self.initial_user,
+                focus_on_load: self.focus_on_load,
+            }
+        }
+    }
+}
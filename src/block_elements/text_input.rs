@@ -0,0 +1,230 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::DispatchActionConfig;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// # Plain Text Input
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements#input)
+///
+/// Works with the following block types: Input
+///
+/// A plain-text input, similar to an HTML `<input type="text">` element.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct PlainTextInput<'a> {
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<text::Plain>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 3000))]
+    initial_value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    multiline: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(max = 3000))]
+    min_length: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(max = 3000))]
+    max_length: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+
+    #[serde(rename = "dispatch_action_configuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    dispatch_action_config: Option<DispatchActionConfig>,
+}
+
+impl<'a> PlainTextInput<'a> {
+    /// Build a new plain-text input.
+    ///
+    /// # Example
+    /// see example for `build::PlainTextInputBuilder`.
+    pub fn builder() -> build::PlainTextInputBuilderInit<'a> {
+        build::PlainTextInputBuilderInit::new()
+    }
+
+    /// Validate that this plain-text input agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `initial_value` is longer than 3000 chars
+    /// - If `min_length` or `max_length` is greater than 3000
+    /// - If `dispatch_action_config` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+/// Plain-text input builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// PlainTextInputBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the PlainTextInput builder
+    pub type PlainTextInputBuilderInit<'a> =
+        PlainTextInputBuilder<'a, RequiredMethodNotCalled<method::action_id>>;
+
+    /// Plain-text input builder
+    ///
+    /// # Required Methods
+    /// `PlainTextInputBuilder::build()` is only available if these methods have been called:
+    ///  - `action_id`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::PlainTextInput;
+    ///
+    /// let input = PlainTextInput::builder().action_id("name_field").build();
+    /// ```
+    #[derive(Debug)]
+    pub struct PlainTextInputBuilder<'a, A> {
+        action_id: Option<Cow<'a, str>>,
+        placeholder: Option<text::Plain>,
+        initial_value: Option<String>,
+        multiline: Option<bool>,
+        min_length: Option<u32>,
+        max_length: Option<u32>,
+        focus_on_load: Option<bool>,
+        dispatch_action_config: Option<DispatchActionConfig>,
+        state: std::marker::PhantomData<A>,
+    }
+
+    impl<'a, A> PlainTextInputBuilder<'a, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            PlainTextInputBuilder {
+                action_id: None,
+                placeholder: None,
+                initial_value: None,
+                multiline: None,
+                min_length: None,
+                max_length: None,
+                focus_on_load: None,
+                dispatch_action_config: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        ///
+        /// An identifier for this action, used to identify the source of
+        /// interaction payloads. Must be unique within a block.
+        /// Maximum length for this field is 255 characters.
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> PlainTextInputBuilder<'a, Set<method::action_id>> {
+            PlainTextInputBuilder {
+                action_id: Some(action_id.into()),
+                placeholder: self.placeholder,
+                initial_value: self.initial_value,
+                multiline: self.multiline,
+                min_length: self.min_length,
+                max_length: self.max_length,
+                focus_on_load: self.focus_on_load,
+                dispatch_action_config: self.dispatch_action_config,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (Optional)
+        ///
+        /// A `plain_text` string shown in the input until a value is typed.
+        pub fn placeholder(mut self, placeholder: impl Into<text::Plain>) -> Self {
+            self.placeholder = Some(placeholder.into());
+            self
+        }
+
+        /// Set `initial_value` (Optional)
+        ///
+        /// The initial value in the plain-text input when it is first rendered.
+        pub fn initial_value(mut self, initial_value: impl ToString) -> Self {
+            self.initial_value = Some(initial_value.to_string());
+            self
+        }
+
+        /// Set `multiline` (Optional)
+        ///
+        /// Whether the input should allow multiple lines of text, rendering
+        /// as a `<textarea>` would.
+        pub fn multiline(mut self, multiline: bool) -> Self {
+            self.multiline = Some(multiline);
+            self
+        }
+
+        /// Set `min_length` (Optional)
+        ///
+        /// The minimum length of input that the user must provide.
+        /// Maximum value is 3000.
+        pub fn min_length(mut self, min_length: u32) -> Self {
+            self.min_length = Some(min_length);
+            self
+        }
+
+        /// Set `max_length` (Optional)
+        ///
+        /// The maximum length of input that the user can provide.
+        /// Maximum value is 3000.
+        pub fn max_length(mut self, max_length: u32) -> Self {
+            self.max_length = Some(max_length);
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this input should be focused as soon as the containing
+        /// surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+
+        /// Set `dispatch_action_config` (Optional)
+        ///
+        /// Configures the events that cause this input to dispatch a
+        /// `block_actions` payload, e.g. on every keystroke rather than
+        /// waiting for the surface to be submitted.
+        pub fn dispatch_action_config(mut self, config: DispatchActionConfig) -> Self {
+            self.dispatch_action_config = Some(config);
+            self
+        }
+    }
+
+    impl<'a> PlainTextInputBuilder<'a, Set<method::action_id>> {
+        /// All done building, now give me a plain-text input!
+        ///
+        /// > `no method name 'build' found for struct 'PlainTextInputBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `PlainTextInputBuilder`.
+        pub fn build(self) -> PlainTextInput<'a> {
+            PlainTextInput {
+                action_id: self.action_id.unwrap(),
+                placeholder: self.placeholder,
+                initial_value: self.initial_value,
+                multiline: self.multiline,
+                min_length: self.min_length,
+                max_length: self.max_length,
+                focus_on_load: self.focus_on_load,
+                dispatch_action_config: self.dispatch_action_config,
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::build::{RequiredMethodNotCalled, Set};
+use crate::compose::DispatchActionConfig;
+use crate::text;
+use crate::val_helpr::ValidationResult;
+
+/// # URL Input
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/block-elements#url)
+///
+/// Works with the following block types: Input
+///
+/// Allows user to enter a URL, e.g. `https://example.com`.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct UrlInput<'a> {
+    #[validate(length(max = 255))]
+    action_id: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<text::Plain>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+
+    #[serde(rename = "dispatch_action_configuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    dispatch_action_config: Option<DispatchActionConfig>,
+}
+
+impl<'a> UrlInput<'a> {
+    /// Build a new URL input.
+    ///
+    /// # Example
+    /// see example for `build::UrlInputBuilder`.
+    pub fn builder() -> build::UrlInputBuilderInit<'a> {
+        build::UrlInputBuilderInit::new()
+    }
+
+    /// Validate that this URL input agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `action_id` is longer than 255 chars
+    /// - If `dispatch_action_config` is set and invalid
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+/// URL input builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// UrlInputBuilder.action_id
+        #[derive(Copy, Clone, Debug)]
+        pub struct action_id;
+    }
+
+    /// Initial state for the UrlInput builder
+    pub type UrlInputBuilderInit<'a> =
+        UrlInputBuilder<'a, RequiredMethodNotCalled<method::action_id>>;
+
+    /// URL input builder
+    ///
+    /// # Required Methods
+    /// `UrlInputBuilder::build()` is only available if these methods have been called:
+    ///  - `action_id`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::UrlInput;
+    ///
+    /// let input = UrlInput::builder().action_id("url_field").build();
+    /// ```
+    #[derive(Debug)]
+    pub struct UrlInputBuilder<'a, A> {
+        action_id: Option<Cow<'a, str>>,
+        placeholder: Option<text::Plain>,
+        initial_value: Option<String>,
+        focus_on_load: Option<bool>,
+        dispatch_action_config: Option<DispatchActionConfig>,
+        state: std::marker::PhantomData<A>,
+    }
+
+    impl<'a, A> UrlInputBuilder<'a, A> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            UrlInputBuilder {
+                action_id: None,
+                placeholder: None,
+                initial_value: None,
+                focus_on_load: None,
+                dispatch_action_config: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `action_id` (**Required**)
+        ///
+        /// An identifier for this action, used to identify the source of
+        /// interaction payloads. Must be unique within a block.
+        /// Maximum length for this field is 255 characters.
+        pub fn action_id(
+            self,
+            action_id: impl Into<Cow<'a, str>>,
+        ) -> UrlInputBuilder<'a, Set<method::action_id>> {
+            UrlInputBuilder {
+                action_id: Some(action_id.into()),
+                placeholder: self.placeholder,
+                initial_value: self.initial_value,
+                focus_on_load: self.focus_on_load,
+                dispatch_action_config: self.dispatch_action_config,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `placeholder` (Optional)
+        ///
+        /// A `plain_text` string shown in the input until a value is typed.
+        pub fn placeholder(mut self, placeholder: impl Into<text::Plain>) -> Self {
+            self.placeholder = Some(placeholder.into());
+            self
+        }
+
+        /// Set `initial_value` (Optional)
+        ///
+        /// The initial value in the URL input when it is first rendered.
+        pub fn initial_value(mut self, initial_value: impl ToString) -> Self {
+            self.initial_value = Some(initial_value.to_string());
+            self
+        }
+
+        /// Set `focus_on_load` (Optional)
+        ///
+        /// Whether this input should be focused as soon as the containing
+        /// surface is rendered.
+        pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+            self.focus_on_load = Some(focus_on_load);
+            self
+        }
+
+        /// Set `dispatch_action_config` (Optional)
+        ///
+        /// Configures the events that cause this input to dispatch a
+        /// `block_actions` payload, e.g. on every keystroke rather than
+        /// waiting for the surface to be submitted.
+        pub fn dispatch_action_config(mut self, config: DispatchActionConfig) -> Self {
+            self.dispatch_action_config = Some(config);
+            self
+        }
+    }
+
+    impl<'a> UrlInputBuilder<'a, Set<method::action_id>> {
+        /// All done building, now give me a URL input!
+        ///
+        /// > `no method name 'build' found for struct 'UrlInputBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `UrlInputBuilder`.
+        pub fn build(self) -> UrlInput<'a> {
+            UrlInput {
+                action_id: self.action_id.unwrap(),
+                placeholder: self.placeholder,
+                initial_value: self.initial_value,
+                focus_on_load: self.focus_on_load,
+                dispatch_action_config: self.dispatch_action_config,
+            }
+        }
+    }
+}
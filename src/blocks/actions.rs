@@ -1,9 +1,14 @@
-use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::convert::{TryFrom, TryInto};
+
+use serde::{Deserialize, Serialize};
 use validator::Validate;
 
 use crate::block_elements;
-use crate::block_elements::{select, Button};
+use crate::block_elements::{
+    select, Button, Checkboxes, DatePicker, DateTimePicker, OverflowMenu, PlainTextInput,
+    RadioButtons,
+};
 use crate::convert;
 use crate::val_helpr::ValidationResult;
 
@@ -16,13 +21,14 @@ use crate::val_helpr::ValidationResult;
 /// [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#actions
 /// [elements 🔗]: https://api.slack.com/reference/messaging/block-elements
 #[derive(Clone, Debug, Default, Deserialize, Hash, PartialEq, Serialize, Validate)]
+#[validate(schema(function = "validation::no_response_url_outside_input"))]
 pub struct Contents<'a> {
     #[validate(length(max = 5))]
     elements: Vec<BlockElement<'a>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(length(max = 255))]
-    block_id: Option<String>,
+    block_id: Option<Cow<'a, str>>,
 }
 
 impl<'a> Contents<'a> {
@@ -33,13 +39,34 @@ impl<'a> Contents<'a> {
     /// use slack_blocks::blocks::{Block, actions};
     ///
     /// let actions = actions::Contents::new();
-    /// let block: Block = actions.into();
+    /// let block: Block<'_> = actions.into();
     /// // < send block to slack's API >
     /// ```
     pub fn new() -> Self {
         Default::default()
     }
 
+    /// Build an Actions block, adding elements one at a time.
+    ///
+    /// Since an Actions block has no required fields, `build()` is
+    /// always available - no compile-time checks are necessary.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::blocks::actions;
+    /// use slack_blocks::block_elements::Button;
+    ///
+    /// let btn = Button::from_text_and_action_id("Click me!", "click_me_123");
+    ///
+    /// let actions = actions::Contents::builder()
+    ///     .element(btn)
+    ///     .block_id("tally_ho")
+    ///     .build();
+    /// ```
+    pub fn builder() -> build::ActionsBuilder<'a> {
+        build::ActionsBuilder::new()
+    }
+
     /// Set the `block_id` for interactions on an existing `actions::Contents`
     ///
     /// # Arguments
@@ -56,11 +83,11 @@ impl<'a> Contents<'a> {
     /// use slack_blocks::blocks::{Block, actions};
     ///
     /// let actions = actions::Contents::new().with_block_id("tally_ho");
-    /// let block: Block = actions.into();
+    /// let block: Block<'_> = actions.into();
     /// // < send block to slack's API >
     /// ```
-    pub fn with_block_id(mut self, block_id: impl ToString) -> Self {
-        self.block_id = Some(block_id.to_string());
+    pub fn with_block_id(mut self, block_id: impl Into<Cow<'a, str>>) -> Self {
+        self.block_id = Some(block_id.into());
         self
     }
 
@@ -98,7 +125,7 @@ impl<'a> Contents<'a> {
     /// # pub fn main() -> Result<(), ()> {
     /// let btn = block_elements::Button::from_text_and_action_id("Button", "123");
     /// let actions = actions::Contents::from_elements(vec![btn.into()])?;
-    /// let block: Block = actions.into();
+    /// let block: Block<'_> = actions.into();
     /// // < send block to slack's API >
     /// # Ok(())
     /// # }
@@ -145,7 +172,7 @@ impl<'a> Contents<'a> {
     /// # pub fn main() {
     /// let btn = block_elements::Button::from_text_and_action_id("Button", "123");
     /// let actions = actions::Contents::from_action_elements(vec![btn.into()]);
-    /// let block: Block = actions.into();
+    /// let block: Block<'_> = actions.into();
     ///
     /// // < send block to slack's API >
     /// # }
@@ -187,6 +214,28 @@ impl<'a> Contents<'a> {
     }
 }
 
+mod validation {
+    use super::*;
+    use crate::val_helpr::{error, ValidatorResult};
+
+    pub fn no_response_url_outside_input(contents: &Contents) -> ValidatorResult {
+        let has_response_url_select = contents.elements.iter().any(|el| match el {
+            BlockElement::SelectConversation(select) => select.response_url_enabled(),
+            _ => false,
+        });
+
+        if has_response_url_select {
+            Err(error(
+                "no_response_url_outside_input",
+                "`response_url_enabled` is only honored by Slack for selects living in an `input` block, \
+                 but this select is in an `actions` block",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
 /// The Block Elements supported in an Action Block.
 ///
 /// This list was pulled from the docs for all [block elements 🔗],
@@ -196,17 +245,33 @@ impl<'a> Contents<'a> {
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
 pub enum BlockElement<'a> {
     Button(Button),
-    Checkboxes,
-    DatePicker,
-    OverflowMenu,
-    PlainInput,
-    RadioButtons,
+    Checkboxes(Checkboxes<'a>),
+    DatePicker(DatePicker<'a>),
+    DateTimePicker(DateTimePicker<'a>),
+    OverflowMenu(OverflowMenu<'a>),
+    PlainInput(PlainTextInput<'a>),
+    RadioButtons(RadioButtons<'a>),
 
     /// All Select types are supported.
     SelectPublicChannel(select::PublicChannel<'a>),
 
     /// All Select types are supported.
     SelectConversation(select::Conversation<'a>),
+
+    /// All multi-select types are supported.
+    SelectMultiStatic(select::multi::MultiStatic<'a>),
+
+    /// All multi-select types are supported.
+    SelectMultiExternal(select::multi::MultiExternal<'a>),
+
+    /// All multi-select types are supported.
+    SelectMultiUser(select::multi::MultiUser<'a>),
+
+    /// All multi-select types are supported.
+    SelectMultiConversation(select::multi::MultiConversation<'a>),
+
+    /// All multi-select types are supported.
+    SelectMultiPublicChannel(select::multi::MultiPublicChannel<'a>),
 }
 
 convert!(impl<'a> From<Vec<self::BlockElement<'a>>> for Contents<'a>
@@ -244,12 +309,18 @@ impl<'a> TryFrom<block_elements::BlockElement<'a>> for self::BlockElement<'a> {
         match el {
             El::SelectPublicChannel(sel) => Ok(SelectPublicChannel(sel)),
             El::SelectConversation(sel) => Ok(SelectConversation(sel)),
-            El::OverflowMenu => Ok(OverflowMenu),
-            El::RadioButtons => Ok(RadioButtons),
+            El::OverflowMenu(menu) => Ok(OverflowMenu(menu)),
+            El::RadioButtons(radios) => Ok(RadioButtons(radios)),
             El::Button(cts) => Ok(Button(cts)),
-            El::PlainInput => Ok(PlainInput),
-            El::Checkboxes => Ok(Checkboxes),
-            El::DatePicker => Ok(DatePicker),
+            El::PlainInput(input) => Ok(PlainInput(input)),
+            El::Checkboxes(checkboxes) => Ok(Checkboxes(checkboxes)),
+            El::DatePicker(picker) => Ok(DatePicker(picker)),
+            El::DateTimePicker(picker) => Ok(DateTimePicker(picker)),
+            El::SelectMultiStatic(sel) => Ok(SelectMultiStatic(sel)),
+            El::SelectMultiExternal(sel) => Ok(SelectMultiExternal(sel)),
+            El::SelectMultiUser(sel) => Ok(SelectMultiUser(sel)),
+            El::SelectMultiConversation(sel) => Ok(SelectMultiConversation(sel)),
+            El::SelectMultiPublicChannel(sel) => Ok(SelectMultiPublicChannel(sel)),
             _ => Err(()),
         }
     }
@@ -260,3 +331,81 @@ use select::PublicChannel as SelectPublicChannel;
 convert!(impl<'a> From<SelectPublicChannel<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectPublicChannel(s));
 convert!(impl<'a> From<SelectConversation<'a>> for BlockElement<'a>  => |s| self::BlockElement::SelectConversation(s));
 convert!(impl     From<Button> for BlockElement<'static> => |b| self::BlockElement::Button(b));
+convert!(impl<'_> From<Checkboxes> for BlockElement => |c| self::BlockElement::Checkboxes(c));
+convert!(impl<'_> From<DatePicker> for BlockElement => |d| self::BlockElement::DatePicker(d));
+convert!(impl<'_> From<DateTimePicker> for BlockElement => |d| self::BlockElement::DateTimePicker(d));
+convert!(impl<'_> From<OverflowMenu> for BlockElement => |o| self::BlockElement::OverflowMenu(o));
+convert!(impl<'_> From<PlainTextInput> for BlockElement => |p| self::BlockElement::PlainInput(p));
+convert!(impl<'_> From<RadioButtons> for BlockElement => |r| self::BlockElement::RadioButtons(r));
+convert!(impl<'a> From<select::multi::MultiStatic<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectMultiStatic(s));
+convert!(impl<'a> From<select::multi::MultiExternal<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectMultiExternal(s));
+convert!(impl<'a> From<select::multi::MultiUser<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectMultiUser(s));
+convert!(impl<'a> From<select::multi::MultiConversation<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectMultiConversation(s));
+convert!(impl<'a> From<select::multi::MultiPublicChannel<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectMultiPublicChannel(s));
+
+/// Actions block builder
+pub mod build {
+    use super::*;
+
+    /// Actions block builder
+    ///
+    /// Unlike most other builders in this crate, `ActionsBuilder` has no
+    /// required setter methods - `build()` is always available, since an
+    /// Actions block is valid with zero elements.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::blocks::actions::Contents;
+    /// use slack_blocks::block_elements::Button;
+    ///
+    /// let btn = Button::from_text_and_action_id("Click me!", "click_me_123");
+    ///
+    /// let actions = Contents::builder().element(btn).build();
+    /// ```
+    #[derive(Debug, Default)]
+    pub struct ActionsBuilder<'a> {
+        elements: Vec<BlockElement<'a>>,
+        block_id: Option<Cow<'a, str>>,
+    }
+
+    impl<'a> ActionsBuilder<'a> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            Default::default()
+        }
+
+        /// Append `element` (Optional, repeatable)
+        ///
+        /// An interactive [element object 🔗] to add to this Actions block.
+        /// There is a maximum of 5 elements in each action block, enforced
+        /// at runtime by `validate`.
+        ///
+        /// [element object 🔗]: https://api.slack.com/reference/messaging/block-elements
+        pub fn element(mut self, element: impl Into<super::BlockElement<'a>>) -> Self {
+            self.elements.push(element.into());
+            self
+        }
+
+        /// Set `block_id` (Optional)
+        ///
+        /// A string acting as a unique identifier for a block.
+        /// You can use this `block_id` when you receive an interaction
+        /// payload to [identify the source of the action 🔗].
+        /// If not specified, one will be generated.
+        /// Maximum length for this field is 255 characters.
+        ///
+        /// [identify the source of the action 🔗]: https://api.slack.com/interactivity/handling#payloads
+        pub fn block_id(mut self, block_id: impl Into<Cow<'a, str>>) -> Self {
+            self.block_id = Some(block_id.into());
+            self
+        }
+
+        /// All done building, now give me an Actions block!
+        pub fn build(self) -> Contents<'a> {
+            Contents {
+                elements: self.elements,
+                block_id: self.block_id,
+            }
+        }
+    }
+}
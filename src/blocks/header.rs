@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::compose;
+use crate::val_helpr::ValidationResult;
+
+/// # Header Block
+///
+/// _[slack api docs 🔗][header_docs]_
+///
+/// A plain-text block that displays as a larger, bold header above
+/// other blocks.
+///
+/// [header_docs]: https://api.slack.com/reference/block-kit/blocks#header
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct Contents {
+    #[validate(custom = "compose::validation::text_is_plain")]
+    #[validate(custom = "compose::validation::header_text_max_len")]
+    text: compose::Text,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 255))]
+    block_id: Option<String>,
+}
+
+impl Contents {
+    /// Construct a Header block from its required `text`.
+    ///
+    /// # Arguments
+    /// - `text` - The text shown as the header.
+    ///     Must be of type `plain_text`. Maximum length for this field is 150 characters.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::blocks::{Block, header};
+    /// use slack_blocks::compose::Text;
+    ///
+    /// let header = header::Contents::from_text(Text::plain("Budget Overruns"));
+    /// let block: Block<'_> = header.into();
+    /// // < send block to slack's API >
+    /// ```
+    pub fn from_text(text: impl Into<compose::Text>) -> Self {
+        Contents {
+            text: text.into(),
+            block_id: None,
+        }
+    }
+
+    /// Set the `block_id` for interactions on an existing `header::Contents`
+    ///
+    /// # Arguments
+    /// - `block_id` - A string acting as a unique identifier for a block.
+    ///     You can use this `block_id` when you receive an interaction payload
+    ///     to identify the source of the action.
+    ///     If not specified, a `block_id` will be generated.
+    ///     Maximum length for this field is 255 characters.
+    pub fn with_block_id(mut self, block_id: impl ToString) -> Self {
+        self.block_id = Some(block_id.to_string());
+        self
+    }
+
+    /// Validate that this Header block agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `text` is not `plain_text`, or is longer than 150 chars
+    /// - If `with_block_id` was called with a block id longer than 255 chars
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::blocks::header;
+    /// use slack_blocks::compose::Text;
+    ///
+    /// let header = header::Contents::from_text(Text::markdown("can't have *mrkdwn* in a header!"));
+    ///
+    /// assert_eq!(true, matches!(header.validate(), Err(_)));
+    /// ```
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::compose::Text;
+
+    #[test]
+    fn header_should_deserialize() {
+        let json = serde_json::json!({
+            "text": {
+                "type": "plain_text",
+                "text": "Budget Overruns",
+                "emoji": null,
+            },
+        });
+
+        let expected = Contents::from_text(Text::plain("Budget Overruns"));
+        let actual: Contents = serde_json::from_value(json).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn header_should_round_trip() {
+        let header = Contents::from_text(Text::plain("Budget Overruns")).with_block_id("header_1");
+
+        let json = serde_json::to_value(&header).unwrap();
+        let round_tripped: Contents = serde_json::from_value(json).unwrap();
+
+        assert_eq!(header, round_tripped);
+    }
+}
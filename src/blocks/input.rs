@@ -1,8 +1,12 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-use crate::block_elements::select;
+use crate::block_elements::{
+    select, Checkboxes, DatePicker, EmailInput, NumberInput, PlainTextInput, RadioButtons, UrlInput,
+};
+use crate::build::{RequiredMethodNotCalled, Set};
 use crate::compose;
+use crate::convert;
 use crate::val_helpr::ValidationResult;
 
 /// # Input Block
@@ -17,11 +21,12 @@ use crate::val_helpr::ValidationResult;
 /// [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#input
 /// [slack's guide to using modals 🔗]: https://api.slack.com/surfaces/modals/using#gathering_input
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
-pub struct Contents {
+#[validate(schema(function = "validation::dispatch_action_supported"))]
+pub struct Contents<'a> {
     #[validate(custom = "validation::text_max_len_2k")]
     label: compose::Text,
 
-    element: InputElement,
+    element: InputElement<'a>,
 
     #[validate(length(max = 255))]
     block_id: Option<String>,
@@ -30,191 +35,47 @@ pub struct Contents {
     hint: Option<compose::Text>,
 
     optional: Option<bool>,
-}
-
-impl Contents {
-    /// Create an Input Block from a text Label and interactive element.
-    ///
-    /// # Arguments
-    ///
-    /// - `label` - A label that appears above an input element in the form of
-    ///     a [text object 🔗] that must have type of `plain_text`.
-    ///     Maximum length for the text in this field is 2000 characters.
-    ///
-    /// - `element` - An interactive `block_element` that will be used to gather
-    ///     the input for this block.
-    ///     For the kinds of Elements supported by
-    ///     Input blocks, see the `InputElement` enum.
-    ///     For info about Block Elements in general,
-    ///     see the `block_elements` module.
-    ///
-    /// [text object 🔗]: https://api.slack.com/reference/messaging/composition-objects#text
-    ///
-    /// # Example
-    /// ```
-    /// use slack_blocks::block_elements::select;
-    /// use slack_blocks::blocks;
-    /// use slack_blocks::compose;
-    ///
-    /// # use std::error::Error;
-    /// # pub fn main() -> Result<(), Box<dyn Error>> {
-    /// let label = compose::Text::plain("On a scale from 1 - 5, how angsty are you?");
-    /// let input = select::Static {};
-    ///
-    /// let block = blocks::input::Contents::from_label_and_element(label, input);
-    ///
-    /// // < send to slack API >
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub fn from_label_and_element<Label: Into<compose::Text>, El: Into<InputElement>>(
-        label: Label,
-        element: El,
-    ) -> Self {
-        Contents {
-            label: label.into(),
-            element: element.into(),
-            block_id: None,
-            hint: None,
-            optional: None,
-        }
-    }
 
-    /// Set a unique `block_id` to identify this instance of an Input Block.
-    ///
-    /// # Arguments
-    ///
-    /// - `block_id` - A string acting as a unique identifier for a block.
-    ///     You can use this `block_id` when you receive an interaction
-    ///     payload to [identify the source of the action 🔗].
-    ///     If not specified, one will be generated.
-    ///     Maximum length for this field is 255 characters.
-    ///     `block_id` should be unique for each message and each iteration of a message.
-    ///     If a message is updated, use a new `block_id`.
-    ///
-    /// [identify the source of the action 🔗]: https://api.slack.com/interactivity/handling#payloads
-    ///
-    /// # Example
-    /// ```
-    /// use slack_blocks::block_elements::select;
-    /// use slack_blocks::blocks;
-    /// use slack_blocks::compose;
-    ///
-    /// # use std::error::Error;
-    /// # pub fn main() -> Result<(), Box<dyn Error>> {
-    /// let label = compose::Text::plain("On a scale from 1 - 5, how angsty are you?");
-    /// let input = select::Static {};
-    ///
-    /// let block = blocks::input
-    ///     ::Contents
-    ///     ::from_label_and_element(label, input)
-    ///     .with_block_id("angst_rating_12345");
-    ///
-    /// // < send to slack API >
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub fn with_block_id<StrIsh: AsRef<str>>(mut self, block_id: StrIsh) -> Self {
-        self.block_id = Some(block_id.as_ref().to_string());
-        self
-    }
-
-    /// Set the `hint` on this Input Block that appears below
-    /// an input element in a lighter grey.
-    ///
-    /// # Arguments
-    ///
-    /// - `hint` - An optional hint that appears below an input element
-    ///     in a lighter grey.
-    ///     It must be a a [text object 🔗] with a `type` of `plain_text`.
-    ///     Maximum length for the `text` in this field is 2000 characters.
-    ///
-    /// [text object 🔗]: https://api.slack.com/reference/messaging/composition-objects#text
-    ///
-    /// # Example
-    /// ```
-    /// use slack_blocks::block_elements::select;
-    /// use slack_blocks::blocks;
-    /// use slack_blocks::compose;
-    ///
-    /// # use std::error::Error;
-    /// # pub fn main() -> Result<(), Box<dyn Error>> {
-    /// let label = compose::Text::plain("On a scale from 1 - 5, how angsty are you?");
-    /// let input = select::Static {};
-    ///
-    /// let block = blocks::input
-    ///     ::Contents
-    ///     ::from_label_and_element(label, input)
-    ///     .with_hint(compose::Text::plain("PSST hey! Don't let them know how angsty you are!"));
-    ///
-    /// // < send to slack API >
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub fn with_hint<IntoText: Into<compose::Text>>(mut self, hint: IntoText) -> Self {
-        self.hint = Some(hint.into());
-        self
-    }
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dispatch_action: Option<bool>,
+}
 
-    /// Set whether or not this input is Optional.
-    ///
-    /// # Arguments
-    /// - `optionality` - A boolean that indicates whether the input
-    ///     element may be empty when a user submits the modal.
-    ///     Defaults to false.
+impl<'a> Contents<'a> {
+    /// Build a new Input block.
     ///
     /// # Example
-    /// ```
-    /// use slack_blocks::block_elements::select;
-    /// use slack_blocks::blocks;
-    /// use slack_blocks::compose;
-    ///
-    /// # use std::error::Error;
-    /// # pub fn main() -> Result<(), Box<dyn Error>> {
-    /// let label = compose::Text::plain("On a scale from 1 - 5, how angsty are you?");
-    /// let input = select::Static {};
-    ///
-    /// let block = blocks::input
-    ///     ::Contents
-    ///     ::from_label_and_element(label, input)
-    ///     .with_hint(compose::Text::plain("PSST hey! Don't even answer that!"))
-    ///     .with_optional(true);
-    ///
-    /// // < send to slack API >
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub fn with_optional(mut self, optionality: bool) -> Self {
-        self.optional = Some(optionality);
-        self
+    /// see example for `build::InputBuilder`.
+    pub fn builder() -> build::InputBuilderInit<'a> {
+        build::InputBuilderInit::new()
     }
 
     /// Validate that this Input block agrees with Slack's model requirements
     ///
     /// # Errors
-    /// - If `from_label_and_element` was passed a Text object longer
-    ///     than 2000 chars
-    /// - If `with_hint` was called with a block id longer
-    ///     than 2000 chars
-    /// - If `with_block_id` was called with a block id longer
-    ///     than 256 chars
+    /// - If `label` is longer than 2000 chars
+    /// - If `hint` is longer than 2000 chars
+    /// - If `block_id` is longer than 255 chars
+    /// - If `dispatch_action` is set on an element that doesn't support
+    ///     dispatching actions (currently: `Checkboxes`, `DatePicker`,
+    ///     `RadioButtons`)
     ///
     /// # Example
     /// ```
-    /// use slack_blocks::block_elements::select;
+    /// use slack_blocks::block_elements::PlainTextInput;
     /// use slack_blocks::blocks;
     /// use slack_blocks::compose;
     ///
     /// # use std::error::Error;
     /// # pub fn main() -> Result<(), Box<dyn Error>> {
     /// let label = compose::Text::plain("On a scale from 1 - 5, how angsty are you?");
-    /// let input = select::Static {};
+    /// let input = PlainTextInput::builder().action_id("angst_rating").build();
     /// let long_string = std::iter::repeat(' ').take(2001).collect::<String>();
     ///
-    /// let block = blocks::input
-    ///     ::Contents
-    ///     ::from_label_and_element(label, input)
-    ///     .with_block_id(long_string);
+    /// let block = blocks::input::Contents::builder()
+    ///     .label(label)
+    ///     .element(input)
+    ///     .block_id(long_string)
+    ///     .build();
     ///
     /// assert_eq!(true, matches!(block.validate(), Err(_)));
     ///
@@ -230,29 +91,253 @@ impl Contents {
 /// Enum representing the [`BlockElement` 🔗] types
 /// supported by InputElement.
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
-pub enum InputElement {
-    Checkboxes,
-    DatePicker,
-    MultiSelect,
-    Select(select::Contents),
-    PlainInput,
-    RadioButtons,
+pub enum InputElement<'a> {
+    Checkboxes(Checkboxes<'a>),
+    DatePicker(DatePicker<'a>),
+    EmailInput(EmailInput<'a>),
+    MultiSelect(select::multi::Multi<'a>),
+    NumberInput(NumberInput<'a>),
+    PlainInput(PlainTextInput<'a>),
+    RadioButtons(RadioButtons<'a>),
+    Select(select::Select<'a>),
+    UrlInput(UrlInput<'a>),
 }
 
-impl<T> From<T> for InputElement
-where
-    T: Into<select::Contents>,
-{
-    fn from(contents: T) -> Self {
-        InputElement::Select(contents.into())
-    }
-}
+convert!(impl<'_> From<Checkboxes> for InputElement => |c| InputElement::Checkboxes(c));
+convert!(impl<'_> From<DatePicker> for InputElement => |d| InputElement::DatePicker(d));
+convert!(impl<'_> From<EmailInput> for InputElement => |e| InputElement::EmailInput(e));
+convert!(impl<'_> From<NumberInput> for InputElement => |n| InputElement::NumberInput(n));
+convert!(impl<'_> From<PlainTextInput> for InputElement => |p| InputElement::PlainInput(p));
+convert!(impl<'_> From<RadioButtons> for InputElement => |r| InputElement::RadioButtons(r));
+convert!(impl<'_> From<UrlInput> for InputElement => |u| InputElement::UrlInput(u));
+
+convert!(impl<'a> From<select::Select<'a>> for InputElement<'a> => |s| InputElement::Select(s));
+convert!(impl<'a> From<select::Static<'a>> for InputElement<'a> => |s| InputElement::Select(select::Select::from(s)));
+convert!(impl<'a> From<select::External<'a>> for InputElement<'a> => |s| InputElement::Select(select::Select::from(s)));
+convert!(impl<'a> From<select::User<'a>> for InputElement<'a> => |s| InputElement::Select(select::Select::from(s)));
+convert!(impl<'a> From<select::Conversation<'a>> for InputElement<'a> => |s| InputElement::Select(select::Select::from(s)));
+convert!(impl<'a> From<select::PublicChannel<'a>> for InputElement<'a> => |s| InputElement::Select(select::Select::from(s)));
+
+convert!(impl<'a> From<select::multi::Multi<'a>> for InputElement<'a> => |m| InputElement::MultiSelect(m));
+convert!(impl<'a> From<select::multi::MultiStatic<'a>> for InputElement<'a> => |m| InputElement::MultiSelect(select::multi::Multi::from(m)));
+convert!(impl<'a> From<select::multi::MultiExternal<'a>> for InputElement<'a> => |m| InputElement::MultiSelect(select::multi::Multi::from(m)));
+convert!(impl<'a> From<select::multi::MultiUser<'a>> for InputElement<'a> => |m| InputElement::MultiSelect(select::multi::Multi::from(m)));
+convert!(impl<'a> From<select::multi::MultiConversation<'a>> for InputElement<'a> => |m| InputElement::MultiSelect(select::multi::Multi::from(m)));
+convert!(impl<'a> From<select::multi::MultiPublicChannel<'a>> for InputElement<'a> => |m| InputElement::MultiSelect(select::multi::Multi::from(m)));
 
 mod validation {
     use crate::compose;
-    use crate::val_helpr::ValidatorResult;
+    use crate::val_helpr::{error, ValidatorResult};
 
     pub fn text_max_len_2k(text: &compose::Text) -> ValidatorResult {
         compose::validation::text_max_len(text, 2000)
     }
+
+    pub fn dispatch_action_supported(contents: &super::Contents) -> ValidatorResult {
+        use super::InputElement::*;
+
+        let element_supports_dispatch = matches!(
+            contents.element,
+            PlainInput(_) | Select(_) | MultiSelect(_) | NumberInput(_) | EmailInput(_) | UrlInput(_)
+        );
+
+        if contents.dispatch_action == Some(true) && !element_supports_dispatch {
+            Err(error(
+                "dispatch_action_supported",
+                "`dispatch_action` is only honored by Slack for `PlainTextInput`, \
+                 select, `NumberInput`, `EmailInput`, and `UrlInput` elements, but \
+                 this Input block's element doesn't support it",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Input block builder
+pub mod build {
+    use super::*;
+
+    /// Required builder methods
+    #[allow(non_camel_case_types)]
+    pub mod method {
+        /// InputBuilder.label
+        #[derive(Copy, Clone, Debug)]
+        pub struct label;
+        /// InputBuilder.element
+        #[derive(Copy, Clone, Debug)]
+        pub struct element;
+    }
+
+    /// Initial state for the Input block builder
+    pub type InputBuilderInit<'a> = InputBuilder<
+        'a,
+        RequiredMethodNotCalled<method::label>,
+        RequiredMethodNotCalled<method::element>,
+    >;
+
+    /// Input block builder
+    ///
+    /// Allows you to construct safely, with compile-time checks
+    /// on required setter methods.
+    ///
+    /// # Required Methods
+    /// `InputBuilder::build()` is only available if these methods have been called:
+    ///  - `label`
+    ///  - `element`
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::block_elements::PlainTextInput;
+    /// use slack_blocks::blocks::input::Contents;
+    /// use slack_blocks::compose::Text;
+    ///
+    /// let input = Contents::builder()
+    ///     .label(Text::plain("On a scale from 1 - 5, how angsty are you?"))
+    ///     .element(PlainTextInput::builder().action_id("angst_rating").build())
+    ///     .build();
+    /// ```
+    #[derive(Debug)]
+    pub struct InputBuilder<'a, L, E> {
+        label: Option<compose::Text>,
+        element: Option<InputElement<'a>>,
+        block_id: Option<String>,
+        hint: Option<compose::Text>,
+        optional: Option<bool>,
+        dispatch_action: Option<bool>,
+        state: std::marker::PhantomData<(L, E)>,
+    }
+
+    impl<'a, L, E> InputBuilder<'a, L, E> {
+        /// Create a new builder
+        pub fn new() -> Self {
+            InputBuilder {
+                label: None,
+                element: None,
+                block_id: None,
+                hint: None,
+                optional: None,
+                dispatch_action: None,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `label` (**Required**)
+        ///
+        /// A label that appears above an input element in the form of
+        /// a [text object 🔗] that must have type of `plain_text`.
+        /// Maximum length for the text in this field is 2000 characters.
+        ///
+        /// [text object 🔗]: https://api.slack.com/reference/messaging/composition-objects#text
+        pub fn label(
+            self,
+            label: impl Into<compose::Text>,
+        ) -> InputBuilder<'a, Set<method::label>, E> {
+            InputBuilder {
+                label: Some(label.into()),
+                element: self.element,
+                block_id: self.block_id,
+                hint: self.hint,
+                optional: self.optional,
+                dispatch_action: self.dispatch_action,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `element` (**Required**)
+        ///
+        /// An interactive `block_element` that will be used to gather
+        /// the input for this block. For the kinds of Elements supported
+        /// by Input blocks, see the `InputElement` enum.
+        pub fn element(
+            self,
+            element: impl Into<InputElement<'a>>,
+        ) -> InputBuilder<'a, L, Set<method::element>> {
+            InputBuilder {
+                label: self.label,
+                element: Some(element.into()),
+                block_id: self.block_id,
+                hint: self.hint,
+                optional: self.optional,
+                dispatch_action: self.dispatch_action,
+                state: std::marker::PhantomData::<_>,
+            }
+        }
+
+        /// Set `block_id` (Optional)
+        ///
+        /// A string acting as a unique identifier for a block.
+        /// You can use this `block_id` when you receive an interaction
+        /// payload to [identify the source of the action 🔗].
+        /// If not specified, one will be generated.
+        /// Maximum length for this field is 255 characters.
+        /// `block_id` should be unique for each message and each iteration of a message.
+        /// If a message is updated, use a new `block_id`.
+        ///
+        /// [identify the source of the action 🔗]: https://api.slack.com/interactivity/handling#payloads
+        pub fn block_id(mut self, block_id: impl ToString) -> Self {
+            self.block_id = Some(block_id.to_string());
+            self
+        }
+
+        /// Set `hint` (Optional)
+        ///
+        /// An optional hint that appears below an input element
+        /// in a lighter grey. It must be a [text object 🔗] with a `type`
+        /// of `plain_text`. Maximum length for the `text` in this field
+        /// is 2000 characters.
+        ///
+        /// [text object 🔗]: https://api.slack.com/reference/messaging/composition-objects#text
+        pub fn hint(mut self, hint: impl Into<compose::Text>) -> Self {
+            self.hint = Some(hint.into());
+            self
+        }
+
+        /// Set `optional` (Optional)
+        ///
+        /// A boolean that indicates whether the input element may be
+        /// empty when a user submits the modal. Defaults to false.
+        pub fn optional(mut self, optional: bool) -> Self {
+            self.optional = Some(optional);
+            self
+        }
+
+        /// Set `dispatch_action` (Optional)
+        ///
+        /// A boolean that indicates whether the underlying element should
+        /// dispatch a `block_actions` payload on user interaction, rather
+        /// than waiting for the surface to be submitted.
+        ///
+        /// Only honored by Slack for elements that support it (currently:
+        /// `PlainTextInput`, `NumberInput`, `EmailInput`, `UrlInput`, and
+        /// select and multi-select elements) - see `validate` for
+        /// the runtime check.
+        pub fn with_dispatch_action(mut self, dispatch_action: bool) -> Self {
+            self.dispatch_action = Some(dispatch_action);
+            self
+        }
+    }
+
+    impl<'a> InputBuilder<'a, Set<method::label>, Set<method::element>> {
+        /// All done building, now give me an Input block!
+        ///
+        /// > `no method name 'build' found for struct 'InputBuilder<...>'`?
+        /// Make sure all required setter methods have been called. See docs for `InputBuilder`.
+        ///
+        /// ```compile_fail
+        /// use slack_blocks::blocks::input::Contents;
+        ///
+        /// let foo = Contents::builder().build(); // Won't compile!
+        /// ```
+        pub fn build(self) -> Contents<'a> {
+            Contents {
+                label: self.label.unwrap(),
+                element: self.element.unwrap(),
+                block_id: self.block_id,
+                hint: self.hint,
+                optional: self.optional,
+                dispatch_action: self.dispatch_action,
+            }
+        }
+    }
 }
@@ -1,22 +1,22 @@
 use serde::{Deserialize, Serialize};
 
-use crate::impl_from_contents;
+use crate::convert;
 
 pub mod actions;
 pub mod context;
-pub mod file;
-pub mod image;
+pub mod header;
 pub mod input;
+pub mod rich_text;
 pub mod section;
 
 type ValidationResult = Result<(), validator::ValidationErrors>;
 
+// NOTE: `image` and `file` blocks aren't implemented yet - see
+// https://github.com/cakekindel/slack-blocks-rs/issues/61. Add `pub mod` and
+// a `Block` variant for each once they exist.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(tag = "type")]
-pub enum Block {
-    #[serde(rename = "section")]
-    Section(section::Contents),
-
+pub enum Block<'a> {
     /// # Divider Block
     ///
     /// _[slack api docs 🔗][divider_docs]_
@@ -30,59 +30,62 @@ pub enum Block {
     #[serde(rename = "divider")]
     Divider,
 
-    #[serde(rename = "image")]
-    Image(image::Contents),
+    #[serde(rename = "section")]
+    Section(section::Contents<'a>),
 
     #[serde(rename = "actions")]
-    Actions(actions::Contents),
+    Actions(actions::Contents<'a>),
 
     #[serde(rename = "context")]
     Context(context::Contents),
 
     #[serde(rename = "input")]
-    Input(input::Contents),
+    Input(input::Contents<'a>),
 
-    #[serde(rename = "file")]
-    File(file::Contents),
+    #[serde(rename = "header")]
+    Header(header::Contents),
+
+    #[serde(rename = "rich_text")]
+    RichText(rich_text::Contents),
 }
 
 use std::fmt;
 
-impl fmt::Display for Block {
+impl<'a> fmt::Display for Block<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let kind = match self {
-            Block::Section { .. } => "Section",
             Block::Divider => "Divider",
-            Block::Image { .. } => "Image",
+            Block::Section { .. } => "Section",
             Block::Actions { .. } => "Actions",
             Block::Context { .. } => "Context",
             Block::Input { .. } => "Input",
-            Block::File { .. } => "File",
+            Block::Header { .. } => "Header",
+            Block::RichText { .. } => "RichText",
         };
 
         write!(f, "{}", kind)
     }
 }
 
-impl Block {
+impl<'a> Block<'a> {
     pub fn validate(&self) -> ValidationResult {
         use Block::*;
 
         match self {
+            Divider => Ok(()),
             Section(contents) => contents.validate(),
-            Image(contents) => contents.validate(),
             Actions(contents) => contents.validate(),
             Context(contents) => contents.validate(),
             Input(contents) => contents.validate(),
-            File(contents) => contents.validate(),
-            other => todo!("validation not implemented for {}", other),
+            Header(contents) => contents.validate(),
+            RichText(contents) => contents.validate(),
         }
     }
 }
 
-impl_from_contents!(Block, Section, section::Contents);
-impl_from_contents!(Block, Image, image::Contents);
-impl_from_contents!(Block, Actions, actions::Contents);
-impl_from_contents!(Block, Context, context::Contents);
-impl_from_contents!(Block, Input, input::Contents);
-impl_from_contents!(Block, File, file::Contents);
+convert!(impl<'a> From<section::Contents<'a>> for Block<'a> => |c| Block::Section(c));
+convert!(impl<'a> From<actions::Contents<'a>> for Block<'a> => |c| Block::Actions(c));
+convert!(impl<'a> From<input::Contents<'a>> for Block<'a> => |c| Block::Input(c));
+convert!(impl<'a> From<context::Contents> for Block<'a> => |c| Block::Context(c));
+convert!(impl<'a> From<header::Contents> for Block<'a> => |c| Block::Header(c));
+convert!(impl<'a> From<rich_text::Contents> for Block<'a> => |c| Block::RichText(c));
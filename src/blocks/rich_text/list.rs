@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use super::section::Section;
+
+/// # Rich Text List Style
+///
+/// Whether a `rich_text_list`'s items are rendered with bullet points
+/// or numbers.
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Style {
+    Bullet,
+    Ordered,
+}
+
+/// # Rich Text List
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/blocks#rich_text_elements)
+///
+/// A bulleted or numbered list, made up of one `Section` per item.
+/// `indent` controls how many levels deep the list is nested.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct List {
+    style: Style,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    indent: Option<u32>,
+
+    elements: Vec<Section>,
+}
+
+impl List {
+    /// Construct a List from its `style` and item `Section`s.
+    pub fn new(style: Style, elements: impl IntoIterator<Item = Section>) -> Self {
+        List {
+            style,
+            indent: None,
+            elements: elements.into_iter().collect(),
+        }
+    }
+
+    /// Set how many levels deep this list is indented/nested.
+    pub fn with_indent(mut self, indent: u32) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::convert;
+use crate::val_helpr::ValidationResult;
+
+pub mod list;
+pub mod preformatted;
+pub mod quote;
+pub mod section;
+pub mod span;
+
+pub use list::List;
+pub use preformatted::Preformatted;
+pub use quote::Quote;
+pub use section::Section;
+pub use span::{Span, Style};
+
+/// # Rich Text Element
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/blocks#rich_text_elements)
+///
+/// One of the four kinds of content a `RichText` block can be made of.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum Element {
+    #[serde(rename = "rich_text_section")]
+    Section(Section),
+
+    #[serde(rename = "rich_text_list")]
+    List(List),
+
+    #[serde(rename = "rich_text_quote")]
+    Quote(Quote),
+
+    #[serde(rename = "rich_text_preformatted")]
+    Preformatted(Preformatted),
+}
+
+convert!(impl From<Section> for Element => |s| Element::Section(s));
+convert!(impl From<List> for Element => |s| Element::List(s));
+convert!(impl From<Quote> for Element => |s| Element::Quote(s));
+convert!(impl From<Preformatted> for Element => |s| Element::Preformatted(s));
+
+/// # Rich Text Block
+///
+/// _[slack api docs 🔗][rich_text_docs]_
+///
+/// Displays formatted, structured text built from sections, lists, quotes,
+/// and preformatted code, rather than the fragile `mrkdwn` string format
+/// described in the `Text::Markdown` docs.
+///
+/// [rich_text_docs]: https://api.slack.com/reference/block-kit/blocks#rich_text
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct Contents {
+    elements: Vec<Element>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 255))]
+    block_id: Option<String>,
+}
+
+impl Contents {
+    /// Construct a Rich Text block from its `Element`s.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::blocks::{Block, rich_text};
+    ///
+    /// let section = rich_text::Section::from_spans(vec![rich_text::Span::text("Hello, world!")]);
+    /// let rich_text = rich_text::Contents::from_elements(vec![section.into()]);
+    /// let block: Block<'_> = rich_text.into();
+    /// // < send block to slack's API >
+    /// ```
+    pub fn from_elements(elements: impl IntoIterator<Item = Element>) -> Self {
+        Contents {
+            elements: elements.into_iter().collect(),
+            block_id: None,
+        }
+    }
+
+    /// Set the `block_id` for interactions on an existing `rich_text::Contents`
+    ///
+    /// # Arguments
+    /// - `block_id` - A string acting as a unique identifier for a block.
+    ///     You can use this `block_id` when you receive an interaction payload
+    ///     to identify the source of the action.
+    ///     If not specified, a `block_id` will be generated.
+    ///     Maximum length for this field is 255 characters.
+    pub fn with_block_id(mut self, block_id: impl ToString) -> Self {
+        self.block_id = Some(block_id.to_string());
+        self
+    }
+
+    /// Validate that this Rich Text block agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `with_block_id` was called with a block id longer than 255 chars
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample() -> Contents {
+        let section = Section::from_spans(vec![Span::text("Hello, world!")]);
+        let list = List::new(list::Style::Bullet, vec![Section::from_spans(vec![Span::emoji("tada")])]);
+        let quote = Quote::from_spans(vec![Span::link("https://example.com")]);
+        let preformatted = Preformatted::from_spans(vec![Span::user("U123")]);
+
+        Contents::from_elements(vec![
+            section.into(),
+            list.into(),
+            quote.into(),
+            preformatted.into(),
+        ])
+        .with_block_id("rich_text_1")
+    }
+
+    #[test]
+    fn rich_text_should_round_trip() {
+        let rich_text = sample();
+
+        let json = serde_json::to_value(&rich_text).unwrap();
+        let round_tripped: Contents = serde_json::from_value(json).unwrap();
+
+        assert_eq!(rich_text, round_tripped);
+    }
+
+    #[test]
+    fn rich_text_should_deserialize() {
+        let json = serde_json::json!({
+            "elements": [
+                {"type": "rich_text_section", "elements": [{"type": "text", "text": "Hello, world!"}]},
+            ],
+            "block_id": "rich_text_1",
+        });
+
+        let expected = Contents::from_elements(vec![
+            Section::from_spans(vec![Span::text("Hello, world!")]).into(),
+        ])
+        .with_block_id("rich_text_1");
+
+        let actual: Contents = serde_json::from_value(json).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use super::span::Span;
+
+/// # Rich Text Preformatted
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/blocks#rich_text_elements)
+///
+/// A sequence of `Span`s rendered as a preformatted code block.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct Preformatted {
+    elements: Vec<Span>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border: Option<u32>,
+}
+
+impl Preformatted {
+    /// Construct a Preformatted block from its `Span`s.
+    pub fn from_spans(elements: impl IntoIterator<Item = Span>) -> Self {
+        Preformatted {
+            elements: elements.into_iter().collect(),
+            border: None,
+        }
+    }
+
+    /// Set the border thickness of the preformatted block.
+    pub fn with_border(mut self, border: u32) -> Self {
+        self.border = Some(border);
+        self
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use super::span::Span;
+
+/// # Rich Text Quote
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/blocks#rich_text_elements)
+///
+/// A sequence of `Span`s rendered as a block quote.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct Quote {
+    elements: Vec<Span>,
+}
+
+impl Quote {
+    /// Construct a Quote from its `Span`s.
+    pub fn from_spans(elements: impl IntoIterator<Item = Span>) -> Self {
+        Quote {
+            elements: elements.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use super::span::Span;
+
+/// # Rich Text Section
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/blocks#rich_text_elements)
+///
+/// A sequence of `Span`s rendered inline, forming a single paragraph of
+/// rich text. Used directly as a `rich_text_section` element, and nested
+/// inside `rich_text_list` items.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct Section {
+    elements: Vec<Span>,
+}
+
+impl Section {
+    /// Construct a Section from its `Span`s.
+    pub fn from_spans(elements: impl IntoIterator<Item = Span>) -> Self {
+        Section {
+            elements: elements.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+/// # Rich Text Style
+///
+/// Boolean formatting flags that can be applied to a `Span::Text` or `Span::Link`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub struct Style {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strike: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<bool>,
+}
+
+impl Style {
+    /// A style with no formatting applied. Use the `with_*` methods to turn flags on.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Render the span's text in **bold**.
+    pub fn with_bold(mut self, bold: bool) -> Self {
+        self.bold = Some(bold);
+        self
+    }
+
+    /// Render the span's text in _italics_.
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = Some(italic);
+        self
+    }
+
+    /// Render the span's text with ~strikethrough~.
+    pub fn with_strike(mut self, strike: bool) -> Self {
+        self.strike = Some(strike);
+        self
+    }
+
+    /// Render the span's text as `inline code`.
+    pub fn with_code(mut self, code: bool) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+/// # Rich Text Span
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/blocks#rich_text_elements)
+///
+/// The smallest unit of content inside a `rich_text_section`, `rich_text_quote`,
+/// or `rich_text_preformatted` element.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum Span {
+    /// A run of plain text, optionally styled.
+    #[serde(rename = "text")]
+    Text {
+        text: String,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<Style>,
+    },
+
+    /// A hyperlink, optionally with display text and styling.
+    #[serde(rename = "link")]
+    Link {
+        url: String,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<Style>,
+    },
+
+    /// An emoji, referenced by its `:name:`.
+    #[serde(rename = "emoji")]
+    Emoji { name: String },
+
+    /// A reference to a user, rendered as a mention.
+    #[serde(rename = "user")]
+    User { user_id: String },
+
+    /// A reference to a channel, rendered as a mention.
+    #[serde(rename = "channel")]
+    Channel { channel_id: String },
+
+    /// A reference to a user group, rendered as a mention.
+    #[serde(rename = "usergroup")]
+    Usergroup { usergroup_id: String },
+}
+
+impl Span {
+    /// Construct a `Span::Text` with no styling.
+    pub fn text(text: impl ToString) -> Self {
+        Span::Text {
+            text: text.to_string(),
+            style: None,
+        }
+    }
+
+    /// Construct a `Span::Text` with the given styling.
+    pub fn styled_text(text: impl ToString, style: Style) -> Self {
+        Span::Text {
+            text: text.to_string(),
+            style: Some(style),
+        }
+    }
+
+    /// Construct a `Span::Link` with no display text or styling.
+    pub fn link(url: impl ToString) -> Self {
+        Span::Link {
+            url: url.to_string(),
+            text: None,
+            style: None,
+        }
+    }
+
+    /// Construct a `Span::Emoji` from its `:name:`.
+    pub fn emoji(name: impl ToString) -> Self {
+        Span::Emoji {
+            name: name.to_string(),
+        }
+    }
+
+    /// Construct a `Span::User` mention from a user id.
+    pub fn user(user_id: impl ToString) -> Self {
+        Span::User {
+            user_id: user_id.to_string(),
+        }
+    }
+
+    /// Construct a `Span::Channel` mention from a channel id.
+    pub fn channel(channel_id: impl ToString) -> Self {
+        Span::Channel {
+            channel_id: channel_id.to_string(),
+        }
+    }
+
+    /// Construct a `Span::Usergroup` mention from a usergroup id.
+    pub fn usergroup(usergroup_id: impl ToString) -> Self {
+        Span::Usergroup {
+            usergroup_id: usergroup_id.to_string(),
+        }
+    }
+}
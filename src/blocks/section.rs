@@ -0,0 +1,325 @@
+use std::borrow::Cow;
+use std::convert::{TryFrom, TryInto};
+
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::block_elements;
+use crate::block_elements::{
+    select, Button, Checkboxes, DatePicker, DateTimePicker, OverflowMenu, RadioButtons,
+};
+use crate::compose;
+use crate::convert;
+use crate::val_helpr::ValidationResult;
+
+/// # Section Block
+///
+/// [slack api docs 🔗]
+///
+/// A `section` is one of the most flexible blocks available -
+/// it can be used as a simple text block, in combination with text
+/// fields, or side-by-side with a single [block element 🔗] as an
+/// accessory.
+///
+/// [slack api docs 🔗]: https://api.slack.com/reference/block-kit/blocks#section
+/// [block element 🔗]: https://api.slack.com/reference/block-kit/block-elements
+#[derive(Clone, Debug, Default, Deserialize, Hash, PartialEq, Serialize, Validate)]
+#[validate(schema(function = "validation::text_or_fields"))]
+#[validate(schema(function = "validation::no_response_url_outside_input"))]
+pub struct Contents<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "compose::validation::section_text_max_len")]
+    text: Option<compose::Text>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validation::fields_text_max_len")]
+    #[validate(length(max = 10))]
+    fields: Option<Vec<compose::Text>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    accessory: Option<BlockElement<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 255))]
+    block_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> Contents<'a> {
+    /// Construct a Section block from its `text`.
+    ///
+    /// # Arguments
+    /// - `text` - The text shown in this section.
+    ///     Maximum length for this field is 3000 characters.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::blocks::{Block, section};
+    /// use slack_blocks::compose::Text;
+    ///
+    /// let section = section::Contents::from_text(Text::markdown("A *section*!"));
+    /// let block: Block<'_> = section.into();
+    /// // < send block to slack's API >
+    /// ```
+    pub fn from_text(text: impl Into<compose::Text>) -> Self {
+        Contents {
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Construct a Section block from its `fields`.
+    ///
+    /// # Arguments
+    /// - `fields` - Text objects rendered in a two-column table alongside
+    ///     each other. Maximum number of items is 10; each must be no
+    ///     longer than 2000 characters.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::blocks::{Block, section};
+    /// use slack_blocks::compose::Text;
+    ///
+    /// let section = section::Contents::from_fields(vec![
+    ///     Text::markdown("*Priority*"),
+    ///     Text::plain("High"),
+    /// ]);
+    /// let block: Block<'_> = section.into();
+    /// // < send block to slack's API >
+    /// ```
+    pub fn from_fields(fields: impl IntoIterator<Item = impl Into<compose::Text>>) -> Self {
+        Contents {
+            fields: Some(fields.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+
+    /// Add a field to this section's `fields`, rendered in a two-column
+    /// table alongside its siblings.
+    pub fn with_field(mut self, field: impl Into<compose::Text>) -> Self {
+        self.fields
+            .get_or_insert_with(Vec::new)
+            .push(field.into());
+        self
+    }
+
+    /// Set the `accessory` [block element 🔗] shown alongside this section's
+    /// `text`/`fields`.
+    ///
+    /// [block element 🔗]: https://api.slack.com/reference/block-kit/block-elements
+    pub fn with_accessory(mut self, accessory: impl Into<BlockElement<'a>>) -> Self {
+        self.accessory = Some(accessory.into());
+        self
+    }
+
+    /// Set the `accessory` from a `block_elements::BlockElement`, which may
+    /// not be supported as a Section's accessory.
+    ///
+    /// If you _can_ create a `section::BlockElement`, either by creating
+    /// one directly or invoking `block_elements::BlockElement::into`,
+    /// use `with_accessory`.
+    ///
+    /// # Errors
+    /// Errors if the `block_elements::BlockElement` is one that is not
+    /// supported as a Section's accessory.
+    ///
+    /// For a list of `BlockElement` types that are supported, see
+    /// `section::BlockElement`.
+    pub fn with_accessory_element(
+        mut self,
+        accessory: block_elements::BlockElement<'a>,
+    ) -> Result<Self, ()> {
+        self.accessory = Some(accessory.try_into()?);
+        Ok(self)
+    }
+
+    /// Set the `block_id` for interactions on an existing `section::Contents`
+    ///
+    /// # Arguments
+    /// - `block_id` - A string acting as a unique identifier for a block.
+    ///     You can use this `block_id` when you receive an interaction payload
+    ///     to identify the source of the action.
+    ///     If not specified, a `block_id` will be generated.
+    ///     Maximum length for this field is 255 characters.
+    pub fn with_block_id(mut self, block_id: impl Into<Cow<'a, str>>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    /// Validate that this Section block agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If neither `text` nor `fields` is set
+    /// - If `text` is longer than 3000 chars
+    /// - If `fields` has more than 10 elements, or any element is longer than 2000 chars
+    /// - If `with_block_id` was called with a block id longer than 255 chars
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::blocks::section;
+    ///
+    /// let block = section::Contents::default();
+    ///
+    /// assert_eq!(true, matches!(block.validate(), Err(_)));
+    /// ```
+    pub fn validate(&self) -> ValidationResult {
+        Validate::validate(self)
+    }
+}
+
+mod validation {
+    use super::Contents;
+    use crate::compose;
+    use crate::val_helpr::{error, ValidatorResult};
+
+    pub fn fields_text_max_len(fields: &[compose::Text]) -> ValidatorResult {
+        fields
+            .iter()
+            .try_for_each(|field| compose::validation::text_max_len(field, 2000))
+    }
+
+    pub fn text_or_fields(section: &Contents) -> ValidatorResult {
+        if section.text.is_none() && section.fields.is_none() {
+            Err(error(
+                "text_or_fields",
+                "Section must set `text`, `fields`, or both",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn no_response_url_outside_input(section: &Contents) -> ValidatorResult {
+        let has_response_url_select = matches!(
+            &section.accessory,
+            Some(super::BlockElement::SelectConversation(select)) if select.response_url_enabled()
+        );
+
+        if has_response_url_select {
+            Err(error(
+                "no_response_url_outside_input",
+                "`response_url_enabled` is only honored by Slack for selects living in an `input` block, \
+                 but this select is in a `section` block",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The Block Elements supported as a Section's `accessory`.
+///
+/// This list was pulled from the docs for all [block elements 🔗],
+/// where each declares the blocks it is usable in.
+///
+/// [block elements 🔗]: https://api.slack.com/reference/block-kit/block-elements
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub enum BlockElement<'a> {
+    Button(Button),
+    Checkboxes(Checkboxes<'a>),
+    DatePicker(DatePicker<'a>),
+    DateTimePicker(DateTimePicker<'a>),
+    OverflowMenu(OverflowMenu<'a>),
+    RadioButtons(RadioButtons<'a>),
+
+    /// Of the single-select menus, only `PublicChannel` and `Conversation`
+    /// are supported; `Static`, `External` and `User` selects are not.
+    SelectPublicChannel(select::PublicChannel<'a>),
+
+    /// See `SelectPublicChannel` for which single-select menus are supported.
+    SelectConversation(select::Conversation<'a>),
+
+    /// All multi-select types are supported.
+    SelectMultiStatic(select::multi::MultiStatic<'a>),
+
+    /// All multi-select types are supported.
+    SelectMultiExternal(select::multi::MultiExternal<'a>),
+
+    /// All multi-select types are supported.
+    SelectMultiUser(select::multi::MultiUser<'a>),
+
+    /// All multi-select types are supported.
+    SelectMultiConversation(select::multi::MultiConversation<'a>),
+
+    /// All multi-select types are supported.
+    SelectMultiPublicChannel(select::multi::MultiPublicChannel<'a>),
+}
+
+impl<'a> TryFrom<block_elements::BlockElement<'a>> for self::BlockElement<'a> {
+    type Error = ();
+    fn try_from(el: block_elements::BlockElement<'a>) -> Result<Self, Self::Error> {
+        use self::BlockElement::*;
+        use block_elements::BlockElement as El;
+
+        match el {
+            El::SelectPublicChannel(sel) => Ok(SelectPublicChannel(sel)),
+            El::SelectConversation(sel) => Ok(SelectConversation(sel)),
+            El::OverflowMenu(menu) => Ok(OverflowMenu(menu)),
+            El::RadioButtons(radios) => Ok(RadioButtons(radios)),
+            El::Button(cts) => Ok(Button(cts)),
+            El::Checkboxes(checkboxes) => Ok(Checkboxes(checkboxes)),
+            El::DatePicker(picker) => Ok(DatePicker(picker)),
+            El::DateTimePicker(picker) => Ok(DateTimePicker(picker)),
+            El::SelectMultiStatic(sel) => Ok(SelectMultiStatic(sel)),
+            El::SelectMultiExternal(sel) => Ok(SelectMultiExternal(sel)),
+            El::SelectMultiUser(sel) => Ok(SelectMultiUser(sel)),
+            El::SelectMultiConversation(sel) => Ok(SelectMultiConversation(sel)),
+            El::SelectMultiPublicChannel(sel) => Ok(SelectMultiPublicChannel(sel)),
+            _ => Err(()),
+        }
+    }
+}
+
+use select::Conversation as SelectConversation;
+use select::PublicChannel as SelectPublicChannel;
+convert!(impl<'a> From<SelectPublicChannel<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectPublicChannel(s));
+convert!(impl<'a> From<SelectConversation<'a>> for BlockElement<'a>  => |s| self::BlockElement::SelectConversation(s));
+convert!(impl     From<Button> for BlockElement<'static> => |b| self::BlockElement::Button(b));
+convert!(impl<'_> From<Checkboxes> for BlockElement => |c| self::BlockElement::Checkboxes(c));
+convert!(impl<'_> From<DatePicker> for BlockElement => |d| self::BlockElement::DatePicker(d));
+convert!(impl<'_> From<DateTimePicker> for BlockElement => |d| self::BlockElement::DateTimePicker(d));
+convert!(impl<'_> From<OverflowMenu> for BlockElement => |o| self::BlockElement::OverflowMenu(o));
+convert!(impl<'_> From<RadioButtons> for BlockElement => |r| self::BlockElement::RadioButtons(r));
+convert!(impl<'a> From<select::multi::MultiStatic<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectMultiStatic(s));
+convert!(impl<'a> From<select::multi::MultiExternal<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectMultiExternal(s));
+convert!(impl<'a> From<select::multi::MultiUser<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectMultiUser(s));
+convert!(impl<'a> From<select::multi::MultiConversation<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectMultiConversation(s));
+convert!(impl<'a> From<select::multi::MultiPublicChannel<'a>> for BlockElement<'a> => |s| self::BlockElement::SelectMultiPublicChannel(s));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::compose::Text;
+
+    #[test]
+    fn section_should_deserialize() {
+        let json = serde_json::json!({
+            "text": {
+                "type": "mrkdwn",
+                "text": "A *section*!",
+                "verbatim": null,
+            },
+        });
+
+        let expected = Contents::from_text(Text::markdown("A *section*!"));
+        let actual: Contents = serde_json::from_value(json).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn section_should_round_trip() {
+        let section = Contents::from_text(Text::markdown("A *section*!"))
+            .with_field(Text::plain("a field"))
+            .with_block_id("section_1");
+
+        let json = serde_json::to_value(&section).unwrap();
+        let round_tripped: Contents = serde_json::from_value(json).unwrap();
+
+        assert_eq!(section, round_tripped);
+    }
+
+    #[test]
+    fn section_requires_text_or_fields() {
+        assert_eq!(true, matches!(Contents::default().validate(), Err(_)));
+    }
+}
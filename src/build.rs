@@ -0,0 +1,18 @@
+//! Shared type-state machinery for this crate's compile-time-checked builders.
+//!
+//! Each builder that has required fields is generic over one marker type per
+//! required setter. Before that setter has been called, the type parameter is
+//! `RequiredMethodNotCalled<method::whatever>`; the setter consumes `self` and
+//! returns a builder with that parameter swapped for `Set<method::whatever>`.
+//! `build()` is only implemented once every parameter is `Set<_>`, so calling
+//! it too early is a compile error that names the method you forgot to call.
+
+use std::marker::PhantomData;
+
+/// A required builder method has not yet been called.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RequiredMethodNotCalled<T>(PhantomData<T>);
+
+/// A required builder method has been called.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Set<T>(PhantomData<T>);
@@ -1,13 +1,15 @@
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
 use crate::impl_from_contents;
+use crate::val_helpr::{ValidationResult as ValidateResult, ValidatorResult};
 
 pub mod validation {
     use crate::val_helpr::error;
-    use validator::ValidationError;
-    type ValidationResult = Result<(), ValidationError>;
 
-    pub fn text_is_plain(text: &super::Text) -> ValidationResult {
+    use super::ValidatorResult;
+
+    pub fn text_is_plain(text: &super::Text) -> ValidatorResult {
         match text {
             super::Text::Markdown { .. } => {
                 Err(error("text_is_plain", "expected plain, got markdown"))
@@ -16,7 +18,7 @@ pub mod validation {
         }
     }
 
-    pub fn text_max_len(text: &super::Text, max_len: usize) -> ValidationResult {
+    pub fn text_max_len(text: &super::Text, max_len: usize) -> ValidatorResult {
         let len = text.text().chars().count();
 
         if len > max_len {
@@ -30,6 +32,71 @@ pub mod validation {
             Ok(())
         }
     }
+
+    pub fn opt_text_max_len(text: &super::Text) -> ValidatorResult {
+        text_max_len(text, 75)
+    }
+
+    pub fn header_text_max_len(text: &super::Text) -> ValidatorResult {
+        text_max_len(text, 150)
+    }
+
+    pub fn section_text_max_len(text: &super::Text) -> ValidatorResult {
+        text_max_len(text, 3000)
+    }
+
+    pub fn plain_text_max_len(text: &super::text::Plain, max_len: usize) -> ValidatorResult {
+        let len = text.text.chars().count();
+
+        if len > max_len {
+            let message = format!(
+                "Confirm field has max len of {}, but got text of len {}.",
+                max_len, len
+            );
+
+            Err(error("plain_text_max_len", message))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn confirm_title_max_len(text: &super::text::Plain) -> ValidatorResult {
+        plain_text_max_len(text, 100)
+    }
+
+    pub fn confirm_text_max_len(text: &super::Text) -> ValidatorResult {
+        text_max_len(text, 300)
+    }
+
+    pub fn confirm_confirm_deny_max_len(text: &super::text::Plain) -> ValidatorResult {
+        plain_text_max_len(text, 30)
+    }
+
+    pub fn trigger_actions_on_not_empty_or_dupe(
+        config: &super::DispatchActionConfig,
+    ) -> ValidatorResult {
+        let actions = &config.trigger_actions_on;
+
+        if actions.is_empty() {
+            return Err(error(
+                "trigger_actions_on_not_empty_or_dupe",
+                "`trigger_actions_on` must not be empty",
+            ));
+        }
+
+        let mut seen: Vec<&super::TriggerAction> = Vec::with_capacity(actions.len());
+        for action in actions {
+            if seen.contains(&action) {
+                return Err(error(
+                    "trigger_actions_on_not_empty_or_dupe",
+                    "`trigger_actions_on` must not contain duplicate entries",
+                ));
+            }
+            seen.push(action);
+        }
+
+        Ok(())
+    }
 }
 
 /// # Composition Objects
@@ -41,9 +108,15 @@ pub mod validation {
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
 pub enum Compose {
     Text(Text),
+    Confirm(Confirm),
+    OptionObject(Opt),
+    OptionGroup(OptGroup),
 }
 
 impl_from_contents!(Compose, Text, Text);
+impl_from_contents!(Compose, Confirm, Confirm);
+impl_from_contents!(Compose, OptionObject, Opt);
+impl_from_contents!(Compose, OptionGroup, OptGroup);
 
 /// # Text Object
 /// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/composition-objects#text)
@@ -164,3 +237,490 @@ impl Text {
         }
     }
 }
+
+/// Standalone, statically-typed wrappers around the two flavors of `Text`.
+///
+/// These are handy when an API (like `Static::placeholder`) needs to make it
+/// clear at compile time that `plain_text` is required, rather than relying on
+/// a runtime check of `Text::Plain` vs `Text::Markdown`.
+pub mod text {
+    use super::Text;
+
+    /// A `plain_text` composition object.
+    ///
+    /// See `Text::Plain` for more info.
+    #[derive(Clone, Debug, Default, Hash, PartialEq)]
+    pub struct Plain {
+        pub text: String,
+        pub emoji: Option<bool>,
+    }
+
+    impl<StrIsh: AsRef<str>> From<StrIsh> for Plain {
+        fn from(text: StrIsh) -> Self {
+            Plain {
+                text: text.as_ref().to_string(),
+                emoji: None,
+            }
+        }
+    }
+
+    impl From<Plain> for Text {
+        fn from(plain: Plain) -> Self {
+            Text::Plain {
+                text: plain.text,
+                emoji: plain.emoji,
+            }
+        }
+    }
+
+    impl<'a> From<&'a str> for Text {
+        fn from(text: &'a str) -> Self {
+            Text::plain(text)
+        }
+    }
+
+    /// An `mrkdwn` composition object.
+    ///
+    /// See `Text::Markdown` for more info.
+    #[derive(Clone, Debug, Default, Hash, PartialEq)]
+    pub struct Mrkdwn {
+        pub text: String,
+        pub verbatim: Option<bool>,
+    }
+
+    impl<StrIsh: AsRef<str>> From<StrIsh> for Mrkdwn {
+        fn from(text: StrIsh) -> Self {
+            Mrkdwn {
+                text: text.as_ref().to_string(),
+                verbatim: None,
+            }
+        }
+    }
+
+    impl From<Mrkdwn> for Text {
+        fn from(md: Mrkdwn) -> Self {
+            Text::Markdown {
+                text: md.text,
+                verbatim: md.verbatim,
+            }
+        }
+    }
+}
+
+/// A builder for assembling `mrkdwn`-formatted text without hand-concatenating
+/// fragile strings.
+///
+/// Every method that accepts a literal text fragment (`text`, `bold`, `italic`, ...)
+/// runs it through `escape` first, so reserved characters (`&`, `<`, `>`) are
+/// always safe to pass straight from user input. The `link`/`*_mention` methods
+/// emit Slack's `<...>` control syntax directly - see `Text::Markdown` for the
+/// full table of forms this builder produces.
+pub mod mrkdwn {
+    use super::Text;
+
+    /// Escape the reserved `mrkdwn` characters (`&`, `<`, `>`) in a literal
+    /// text segment.
+    ///
+    /// This must run before a segment is wrapped in any of Slack's control
+    /// syntax (`*bold*`, `<url|label>`, etc), not after, or the control
+    /// characters themselves would be escaped.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::compose::mrkdwn;
+    ///
+    /// assert_eq!(mrkdwn::escape("Q&A <3"), "Q&amp;A &lt;3");
+    /// ```
+    pub fn escape(text: impl AsRef<str>) -> String {
+        text.as_ref()
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    }
+
+    /// Incrementally assemble a block of `mrkdwn`-formatted text.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::compose::{mrkdwn::Builder, Text};
+    ///
+    /// let text: Text = Builder::new()
+    ///     .text("Hey ")
+    ///     .user_mention("U1234")
+    ///     .text(", check out ")
+    ///     .link("https://www.cheese.com", "this")
+    ///     .text("!")
+    ///     .into();
+    /// ```
+    #[derive(Clone, Debug, Default, Hash, PartialEq)]
+    pub struct Builder {
+        text: String,
+    }
+
+    impl Builder {
+        /// Create a new, empty `mrkdwn` builder.
+        pub fn new() -> Self {
+            Default::default()
+        }
+
+        /// Append a segment of plain text, escaping reserved characters.
+        pub fn text(mut self, text: impl AsRef<str>) -> Self {
+            self.text.push_str(&escape(text));
+            self
+        }
+
+        /// Append a literal line break.
+        pub fn line_break(mut self) -> Self {
+            self.text.push('\n');
+            self
+        }
+
+        /// Append `*bold*` text.
+        pub fn bold(mut self, text: impl AsRef<str>) -> Self {
+            self.text.push_str(&format!("*{}*", escape(text)));
+            self
+        }
+
+        /// Append `_italic_` text.
+        pub fn italic(mut self, text: impl AsRef<str>) -> Self {
+            self.text.push_str(&format!("_{}_", escape(text)));
+            self
+        }
+
+        /// Append `~strike~` text.
+        pub fn strike(mut self, text: impl AsRef<str>) -> Self {
+            self.text.push_str(&format!("~{}~", escape(text)));
+            self
+        }
+
+        /// Append `` `code` ``.
+        pub fn code(mut self, text: impl AsRef<str>) -> Self {
+            self.text.push_str(&format!("`{}`", escape(text)));
+            self
+        }
+
+        /// Append a `` ```multiline code block``` ``.
+        pub fn code_block(mut self, text: impl AsRef<str>) -> Self {
+            self.text.push_str(&format!("```{}```", escape(text)));
+            self
+        }
+
+        /// Append a `> block quote`, one `>`-prefixed line per line of `text`.
+        pub fn quote(mut self, text: impl AsRef<str>) -> Self {
+            let quoted = escape(text)
+                .lines()
+                .map(|line| format!("> {}", line))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            self.text.push_str(&quoted);
+            self
+        }
+
+        /// Append a ` - li` bulleted list, one item per element of `items`.
+        pub fn bullet_list(mut self, items: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+            let list = items
+                .into_iter()
+                .map(|item| format!(" - {}", escape(item)))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            self.text.push_str(&list);
+            self
+        }
+
+        /// Append a `<url|label>` link.
+        pub fn link(mut self, url: impl AsRef<str>, label: impl AsRef<str>) -> Self {
+            self.text
+                .push_str(&format!("<{}|{}>", escape(url), escape(label)));
+            self
+        }
+
+        /// Append a `<@user_id>` user mention.
+        pub fn user_mention(mut self, user_id: impl AsRef<str>) -> Self {
+            self.text.push_str(&format!("<@{}>", escape(user_id)));
+            self
+        }
+
+        /// Append a `<#channel_id>` channel mention.
+        pub fn channel_mention(mut self, channel_id: impl AsRef<str>) -> Self {
+            self.text.push_str(&format!("<#{}>", escape(channel_id)));
+            self
+        }
+
+        /// Append a `<!subteam^usergroup_id>` user group mention.
+        pub fn usergroup_mention(mut self, usergroup_id: impl AsRef<str>) -> Self {
+            self.text
+                .push_str(&format!("<!subteam^{}>", escape(usergroup_id)));
+            self
+        }
+
+        /// Finish building, producing the assembled `mrkdwn` string.
+        pub fn build(self) -> String {
+            self.text
+        }
+    }
+
+    impl From<Builder> for Text {
+        fn from(builder: Builder) -> Self {
+            Text::markdown(builder.build())
+        }
+    }
+}
+
+/// # Option Object
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/composition-objects#option)
+///
+/// An object that represents a single selectable item in a [`Static`](crate::block_elements::select::Static)
+/// select menu, radio button group, checkbox group, or overflow menu.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct Opt {
+    #[validate(custom = "validation::opt_text_max_len")]
+    text: Text,
+
+    #[validate(length(max = 75))]
+    value: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validation::opt_text_max_len")]
+    description: Option<Text>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(url)]
+    url: Option<String>,
+}
+
+impl Opt {
+    /// Construct an Option object from its required fields.
+    ///
+    /// # Arguments
+    /// - `text` - A [text object 🔗] that defines the text shown in the option on the menu.
+    ///     Maximum length for this field is 75 characters.
+    /// - `value` - A unique string value that will be passed to your app when this option
+    ///     is chosen. Maximum length for this field is 75 characters.
+    ///
+    /// [text object 🔗]: https://api.slack.com/reference/block-kit/composition-objects#text
+    pub fn new(text: impl Into<Text>, value: impl ToString) -> Self {
+        Opt {
+            text: text.into(),
+            value: value.to_string(),
+            description: None,
+            url: None,
+        }
+    }
+
+    /// Set an optional secondary description, shown below the `text` field.
+    pub fn with_description(mut self, description: impl Into<Text>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set a URL to load in the user's browser when this option is clicked.
+    ///
+    /// Only valid in [`OverflowMenu`](crate::block_elements::OverflowMenu) options -
+    /// other elements will ignore this field.
+    pub fn with_url(mut self, url: impl ToString) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    /// Validate that this Option object agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `text` or `description` is longer than 75 chars
+    /// - If `value` is longer than 75 chars
+    pub fn validate(&self) -> ValidateResult {
+        Validate::validate(self)
+    }
+
+    /// The `value` that identifies this option, as submitted back by Slack
+    /// when it appears in a `block_actions`/`view_submission` payload.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+/// # Option Group Object
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/composition-objects#option_group)
+///
+/// Provides a way to group options in a [`Static`](crate::block_elements::select::Static) select
+/// menu or [`MultiStatic`](crate::block_elements::select::multi::MultiStatic) multi-select menu.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct OptGroup {
+    #[validate(custom = "validation::text_is_plain")]
+    #[validate(custom = "validation::opt_text_max_len")]
+    label: Text,
+
+    #[validate]
+    #[validate(length(max = 100))]
+    options: Vec<Opt>,
+}
+
+impl OptGroup {
+    /// Construct an Option Group from a `plain_text` label and its options.
+    ///
+    /// # Arguments
+    /// - `label` - A [text object 🔗] that defines the label shown above this group of options.
+    ///     Must be of type `plain_text`. Maximum length for this field is 75 characters.
+    /// - `options` - An array of [option objects 🔗] that belong to this specific group.
+    ///     Maximum number of options is 100.
+    ///
+    /// [text object 🔗]: https://api.slack.com/reference/block-kit/composition-objects#text
+    /// [option objects 🔗]: https://api.slack.com/reference/block-kit/composition-objects#option
+    pub fn new(label: impl Into<Text>, options: impl IntoIterator<Item = Opt>) -> Self {
+        OptGroup {
+            label: label.into(),
+            options: options.into_iter().collect(),
+        }
+    }
+
+    /// Validate that this Option Group agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `label` is not `plain_text`, or is longer than 75 chars
+    /// - If `options` has more than 100 elements, or contains an invalid `Opt`
+    pub fn validate(&self) -> ValidateResult {
+        Validate::validate(self)
+    }
+
+    pub(crate) fn options(&self) -> &[Opt] {
+        &self.options
+    }
+}
+
+impl<'a> From<&'a Opt> for Opt {
+    fn from(opt: &'a Opt) -> Self {
+        opt.clone()
+    }
+}
+
+/// # Confirmation Dialog Object
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/composition-objects#confirm)
+///
+/// An object that defines a dialog that provides a confirmation step to
+/// any interactive element. This dialog will ask the user to confirm their
+/// action by offering a confirm and deny button.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+pub struct Confirm {
+    #[validate(custom = "validation::confirm_title_max_len")]
+    title: text::Plain,
+
+    #[validate(custom = "validation::confirm_text_max_len")]
+    text: Text,
+
+    #[validate(custom = "validation::confirm_confirm_deny_max_len")]
+    confirm: text::Plain,
+
+    #[validate(custom = "validation::confirm_confirm_deny_max_len")]
+    deny: text::Plain,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<ConfirmStyle>,
+}
+
+impl Confirm {
+    /// Construct a Confirm dialog from its required fields.
+    ///
+    /// # Arguments
+    /// - `title` - A `plain_text` field shown as the header of the dialog. Maximum length
+    ///     for this field is 100 characters.
+    /// - `text` - A text object shown in the body of the dialog. Maximum length for the
+    ///     `text` field in this object is 300 characters.
+    /// - `confirm` - A `plain_text` field shown on the button that confirms the action.
+    ///     Maximum length for this field is 30 characters.
+    /// - `deny` - A `plain_text` field shown on the button that cancels the action.
+    ///     Maximum length for this field is 30 characters.
+    pub fn new(
+        title: impl Into<text::Plain>,
+        text: impl Into<Text>,
+        confirm: impl Into<text::Plain>,
+        deny: impl Into<text::Plain>,
+    ) -> Self {
+        Confirm {
+            title: title.into(),
+            text: text.into(),
+            confirm: confirm.into(),
+            deny: deny.into(),
+            style: None,
+        }
+    }
+
+    /// Color the confirm button as `primary` or `danger`, instead of the default style.
+    pub fn with_style(mut self, style: ConfirmStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Validate that this Confirm dialog agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If `title` is longer than 100 chars
+    /// - If `text` is longer than 300 chars
+    /// - If `confirm` or `deny` is longer than 30 chars
+    pub fn validate(&self) -> ValidateResult {
+        Validate::validate(self)
+    }
+}
+
+/// The decoration applied to a `Confirm` dialog's confirm button.
+#[derive(Copy, Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmStyle {
+    Primary,
+    Danger,
+}
+
+/// # Dispatch Action Configuration
+/// [_slack api docs 🔗_](https://api.slack.com/reference/block-kit/composition-objects#dispatch_action_config)
+///
+/// Determines when an input-capable element (e.g. `PlainInput`, or a
+/// supporting `select` menu) dispatches a `block_actions` payload on user
+/// interaction, rather than waiting for the surface it lives in to be
+/// submitted.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize, Validate)]
+#[validate(schema(function = "validation::trigger_actions_on_not_empty_or_dupe"))]
+pub struct DispatchActionConfig {
+    trigger_actions_on: Vec<TriggerAction>,
+}
+
+impl DispatchActionConfig {
+    /// Construct a Dispatch Action Configuration from the set of events that
+    /// should trigger a `block_actions` payload.
+    ///
+    /// # Arguments
+    /// - `trigger_actions_on` - The interactions that should cause a payload
+    ///     to be dispatched. See `TriggerAction`.
+    ///
+    /// # Example
+    /// ```
+    /// use slack_blocks::compose::{DispatchActionConfig, TriggerAction};
+    ///
+    /// let config = DispatchActionConfig::new(vec![TriggerAction::OnEnterPressed]);
+    /// ```
+    pub fn new(trigger_actions_on: impl IntoIterator<Item = TriggerAction>) -> Self {
+        Self {
+            trigger_actions_on: trigger_actions_on.into_iter().collect(),
+        }
+    }
+
+    /// Validate that this Dispatch Action Configuration agrees with Slack's
+    /// model requirements
+    ///
+    /// # Errors
+    /// - If `trigger_actions_on` is empty
+    /// - If `trigger_actions_on` contains a duplicate entry
+    pub fn validate(&self) -> ValidateResult {
+        Validate::validate(self)
+    }
+}
+
+/// The user interaction(s) that cause a `block_actions` payload to be
+/// dispatched for an element with a `DispatchActionConfig`.
+#[derive(Copy, Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerAction {
+    /// Dispatch when the user presses the enter key while the element is focused.
+    OnEnterPressed,
+    /// Dispatch on every keystroke.
+    OnCharacterEntered,
+}
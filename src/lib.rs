@@ -40,7 +40,10 @@ extern crate validator_derive;
 
 pub mod block_elements;
 pub mod blocks;
+pub mod build;
 pub mod compose;
+pub mod message;
+pub mod response;
 mod val_helpr;
 
 pub use compose::text;
@@ -0,0 +1,138 @@
+//! # Message
+//!
+//! A top-level container for the blocks, fallback text, and attachments
+//! that make up a `chat.postMessage` (or similar) payload.
+//!
+//! [_slack api docs 🔗_](https://api.slack.com/methods/chat.postMessage)
+
+use serde::{Deserialize, Serialize};
+
+use crate::blocks::Block;
+use crate::val_helpr::ValidationResult;
+
+/// # Message
+///
+/// The envelope sent to Slack's messaging APis (e.g. `chat.postMessage`),
+/// bundling the `blocks` that make up the message with a plain-text
+/// fallback and any legacy `attachments`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Message<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blocks: Option<Vec<Block<'a>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<Attachment<'a>>>,
+}
+
+impl<'a> Message<'a> {
+    /// Create an empty Message (shorthand for `Default::default()`)
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the fallback `text` shown in notifications and on clients
+    /// that don't support `blocks`.
+    pub fn with_text(mut self, text: impl ToString) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Set the `blocks` that make up this message.
+    pub fn with_blocks(mut self, blocks: impl IntoIterator<Item = Block<'a>>) -> Self {
+        self.blocks = Some(blocks.into_iter().collect());
+        self
+    }
+
+    /// Set the legacy `attachments` for this message.
+    pub fn with_attachments(
+        mut self,
+        attachments: impl IntoIterator<Item = Attachment<'a>>,
+    ) -> Self {
+        self.attachments = Some(attachments.into_iter().collect());
+        self
+    }
+
+    /// Validate that this Message agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If any contained `Block` is itself invalid
+    /// - If any contained `Attachment` is itself invalid
+    pub fn validate(&self) -> ValidationResult {
+        if let Some(blocks) = &self.blocks {
+            for block in blocks {
+                block.validate()?;
+            }
+        }
+
+        if let Some(attachments) = &self.attachments {
+            for attachment in attachments {
+                attachment.validate()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// # Attachment
+/// [_slack api docs 🔗_](https://api.slack.com/reference/messaging/attachments)
+///
+/// Legacy secondary attachment for a message. New integrations should
+/// prefer `blocks` on `Message` directly, but attachments are still useful
+/// for their `color` bar and as a fallback container for `blocks`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Attachment<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blocks: Option<Vec<Block<'a>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+impl<'a> Attachment<'a> {
+    /// Create an empty Attachment (shorthand for `Default::default()`)
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the `blocks` contained in this attachment.
+    pub fn with_blocks(mut self, blocks: impl IntoIterator<Item = Block<'a>>) -> Self {
+        self.blocks = Some(blocks.into_iter().collect());
+        self
+    }
+
+    /// Set the color of the vertical bar shown alongside this attachment.
+    ///
+    /// Accepts a hex color code (e.g. `#36a64f`) or one of `good`, `warning`, `danger`.
+    pub fn with_color(mut self, color: impl ToString) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Set the fallback `text` for this attachment, shown in notifications
+    /// and on clients that don't support `blocks`.
+    pub fn with_text(mut self, text: impl ToString) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Validate that this Attachment agrees with Slack's model requirements
+    ///
+    /// # Errors
+    /// - If any contained `Block` is itself invalid
+    pub fn validate(&self) -> ValidationResult {
+        if let Some(blocks) = &self.blocks {
+            for block in blocks {
+                block.validate()?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+//! # Response
+//!
+//! This crate is mostly concerned with building blocks to send to Slack,
+//! but Slack also sends data back: `block_actions` and `view_submission`
+//! interaction payloads report what a user selected or typed into the
+//! interactive elements you built.
+//!
+//! This module models the `state.values` portion of those payloads, so you
+//! can decode a user's choices against the same `action_id` you used to
+//! build the element.
+
+pub mod state;
+pub use state::{StateValue, SubmittedState};
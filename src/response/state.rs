@@ -0,0 +1,245 @@
+//! # Submitted State
+//!
+//! Models Slack's `state.values` object, included in `block_actions` and
+//! `view_submission` interaction payloads, that reports what a user has
+//! selected/entered in the interactive elements of a message or modal.
+//!
+//! [_slack api docs 🔗_](https://api.slack.com/reference/interaction-payloads)
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::compose::Opt;
+
+/// # Submitted State
+///
+/// The full `state.values` object from an interaction payload: state is
+/// grouped first by the `block_id` of the block the user interacted with,
+/// then by that block element's `action_id`.
+///
+/// Build an element with a given `action_id` (e.g. `Checkboxes::builder().action_id("fruits")`),
+/// then use that same `action_id` to look up what the user submitted.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct SubmittedState {
+    values: HashMap<String, HashMap<String, StateValue>>,
+}
+
+impl SubmittedState {
+    /// Look up the value submitted for `action_id`, regardless of which
+    /// `block_id` it was nested under.
+    ///
+    /// Returns `None` if no block in this state contains `action_id`.
+    pub fn value(&self, action_id: &str) -> Option<&StateValue> {
+        self.values.values().find_map(|block| block.get(action_id))
+    }
+
+    /// The `value` of each `Opt` selected for `action_id`.
+    ///
+    /// Works for any element that reports `selected_option`/`selected_options`
+    /// (`Checkboxes`, `RadioButtons`, and the `Static`/`MultiStatic` select
+    /// menus). Returns an empty `Vec` if `action_id` isn't present in this
+    /// state, or the element found there doesn't carry selected options.
+    pub fn selected_values(&self, action_id: &str) -> Vec<&str> {
+        self.value(action_id)
+            .map(StateValue::selected_options)
+            .unwrap_or_default()
+            .into_iter()
+            .map(Opt::value)
+            .collect()
+    }
+}
+
+/// # Submitted State Value
+///
+/// A single element's submitted value, tagged with the Slack `type` string
+/// (e.g. `"checkboxes"`) that identifies which element reported it.
+///
+/// [_slack api docs 🔗_](https://api.slack.com/reference/interaction-payloads)
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum StateValue {
+    /// Submitted by a `Checkboxes` element.
+    #[serde(rename = "checkboxes")]
+    Checkboxes {
+        /// The options that were checked when the user submitted.
+        selected_options: Vec<Opt>,
+    },
+
+    /// Submitted by a `RadioButtons` element.
+    #[serde(rename = "radio_buttons")]
+    RadioButtons {
+        /// The option that was selected, if any.
+        selected_option: Option<Opt>,
+    },
+
+    /// Submitted by a `Static` select menu.
+    #[serde(rename = "static_select")]
+    StaticSelect {
+        /// The option that was selected, if any.
+        selected_option: Option<Opt>,
+    },
+
+    /// Submitted by a `MultiStatic` select menu.
+    #[serde(rename = "multi_static_select")]
+    MultiStaticSelect {
+        /// The options that were selected.
+        selected_options: Vec<Opt>,
+    },
+
+    /// Submitted by a `PlainTextInput` element.
+    #[serde(rename = "plain_text_input")]
+    PlainTextInput {
+        /// The text the user entered, if any.
+        value: Option<String>,
+    },
+
+    /// Some other element `type` that this crate doesn't yet model.
+    #[serde(other)]
+    Other,
+}
+
+impl StateValue {
+    /// The `Opt`s this value reports as selected.
+    ///
+    /// Empty for variants that don't carry `selected_option`/`selected_options`
+    /// (e.g. `PlainTextInput`, `Other`).
+    pub fn selected_options(&self) -> Vec<&Opt> {
+        match self {
+            StateValue::Checkboxes { selected_options }
+            | StateValue::MultiStaticSelect { selected_options } => selected_options.iter().collect(),
+
+            StateValue::RadioButtons { selected_option }
+            | StateValue::StaticSelect { selected_option } => selected_option.iter().collect(),
+
+            StateValue::PlainTextInput { .. } | StateValue::Other => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn checkboxes_should_deserialize() {
+        let json = serde_json::json!({
+            "type": "checkboxes",
+            "selected_options": [{"text": {"type": "plain_text", "text": "Apple"}, "value": "apple"}],
+        });
+
+        let expected = StateValue::Checkboxes {
+            selected_options: vec![Opt::new("Apple", "apple")],
+        };
+
+        let actual: StateValue = serde_json::from_value(json).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn radio_buttons_should_deserialize() {
+        let json = serde_json::json!({
+            "type": "radio_buttons",
+            "selected_option": {"text": {"type": "plain_text", "text": "Apple"}, "value": "apple"},
+        });
+
+        let expected = StateValue::RadioButtons {
+            selected_option: Some(Opt::new("Apple", "apple")),
+        };
+
+        let actual: StateValue = serde_json::from_value(json).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn static_select_should_deserialize() {
+        let json = serde_json::json!({
+            "type": "static_select",
+            "selected_option": {"text": {"type": "plain_text", "text": "Apple"}, "value": "apple"},
+        });
+
+        let expected = StateValue::StaticSelect {
+            selected_option: Some(Opt::new("Apple", "apple")),
+        };
+
+        let actual: StateValue = serde_json::from_value(json).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn multi_static_select_should_deserialize() {
+        let json = serde_json::json!({
+            "type": "multi_static_select",
+            "selected_options": [
+                {"text": {"type": "plain_text", "text": "Apple"}, "value": "apple"},
+                {"text": {"type": "plain_text", "text": "Banana"}, "value": "banana"},
+            ],
+        });
+
+        let expected = StateValue::MultiStaticSelect {
+            selected_options: vec![Opt::new("Apple", "apple"), Opt::new("Banana", "banana")],
+        };
+
+        let actual: StateValue = serde_json::from_value(json).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn plain_text_input_should_deserialize() {
+        let json = serde_json::json!({
+            "type": "plain_text_input",
+            "value": "hello!",
+        });
+
+        let expected = StateValue::PlainTextInput {
+            value: Some(String::from("hello!")),
+        };
+
+        let actual: StateValue = serde_json::from_value(json).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn unrecognized_type_should_deserialize_to_other() {
+        let json = serde_json::json!({
+            "type": "some_future_element",
+            "whatever_fields_it_has": "don't matter",
+        });
+
+        let actual: StateValue = serde_json::from_value(json).unwrap();
+
+        assert_eq!(StateValue::Other, actual);
+    }
+
+    #[test]
+    fn submitted_state_should_deserialize_and_look_up_by_action_id() {
+        let json = serde_json::json!({
+            "values": {
+                "block_1": {
+                    "fruits": {
+                        "type": "multi_static_select",
+                        "selected_options": [
+                            {"text": {"type": "plain_text", "text": "Apple"}, "value": "apple"},
+                        ],
+                    },
+                },
+            },
+        });
+
+        let state: SubmittedState = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            Some(&StateValue::MultiStaticSelect {
+                selected_options: vec![Opt::new("Apple", "apple")],
+            }),
+            state.value("fruits")
+        );
+        assert_eq!(vec!["apple"], state.selected_values("fruits"));
+        assert_eq!(None, state.value("nonexistent"));
+    }
+}
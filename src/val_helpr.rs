@@ -0,0 +1,77 @@
+//! Internal helpers shared by the `validate()` implementations scattered
+//! throughout this crate.
+//!
+//! Nothing here is part of the public API; it exists so that each block
+//! and element's `validate` method can lean on the `validator` crate
+//! without re-deriving the same boilerplate every time.
+
+use std::borrow::Cow;
+
+use validator::ValidationError;
+
+/// The result of validating an entire model (a block, an element, etc).
+pub type ValidationResult = Result<(), validator::ValidationErrors>;
+
+/// The result of a single `#[validate(custom = "...")]` field validator.
+pub type ValidatorResult = Result<(), ValidationError>;
+
+/// Build a `ValidationError` with a human-readable message.
+///
+/// This exists because `ValidationError::new` only takes a `'static` code,
+/// with no easy way to attach a formatted message.
+pub fn error(code: &'static str, message: impl Into<Cow<'static, str>>) -> ValidationError {
+    let mut err = ValidationError::new(code);
+    err.message = Some(message.into());
+    err
+}
+
+/// Assert that `items` has at most `max` elements.
+pub fn below_len<T>(field: &'static str, max: u64, items: &[T]) -> ValidatorResult {
+    let len = items.len() as u64;
+
+    if len > max {
+        let message = format!(
+            "{} has a max length of {}, but got {} elements",
+            field, max, len
+        );
+
+        Err(error("below_len", message))
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that every element of `actual` is also present in `allowed`
+/// (compared via `PartialEq`).
+///
+/// Slack requires several "initial selection" fields (`initial_option`,
+/// `initial_options`, ...) to exactly match an entry in the accompanying
+/// `options`/`option_groups` list; sending one that doesn't is silently
+/// rejected by the API rather than raising an error. This collects *every*
+/// mismatch instead of bailing on the first, so the resulting message can
+/// name them all at once.
+pub fn is_subset<'a, T>(
+    field: &'static str,
+    actual: impl IntoIterator<Item = &'a T>,
+    allowed: impl IntoIterator<Item = &'a T>,
+) -> ValidatorResult
+where
+    T: PartialEq + std::fmt::Debug + 'a,
+{
+    let allowed: Vec<&T> = allowed.into_iter().collect();
+    let bad: Vec<&T> = actual
+        .into_iter()
+        .filter(|item| !allowed.contains(item))
+        .collect();
+
+    if bad.is_empty() {
+        Ok(())
+    } else {
+        let message = format!(
+            "{} must be a subset of the accompanying options, but these values were not found there: {:?}",
+            field, bad
+        );
+
+        Err(error("is_subset", message))
+    }
+}